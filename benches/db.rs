@@ -0,0 +1,49 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use egui_fader::{
+    amplitude_to_db, amplitude_to_db_fast, block_peak_db, db_to_amplitude, db_to_amplitude_fast,
+};
+
+fn conversions(c: &mut Criterion) {
+    let amplitudes: Vec<f32> = (1..=1024).map(|it| it as f32 / 1024.0).collect();
+    let levels: Vec<f32> = (0..1024).map(|it| -100.0 + it as f32 * 0.11).collect();
+    c.bench_function("amplitude_to_db", |b| {
+        b.iter(|| {
+            amplitudes
+                .iter()
+                .map(|&it| amplitude_to_db(black_box(it)))
+                .sum::<f32>()
+        })
+    });
+    c.bench_function("amplitude_to_db_fast", |b| {
+        b.iter(|| {
+            amplitudes
+                .iter()
+                .map(|&it| amplitude_to_db_fast(black_box(it)))
+                .sum::<f32>()
+        })
+    });
+    c.bench_function("db_to_amplitude", |b| {
+        b.iter(|| {
+            levels
+                .iter()
+                .map(|&it| db_to_amplitude(black_box(it)))
+                .sum::<f32>()
+        })
+    });
+    c.bench_function("db_to_amplitude_fast", |b| {
+        b.iter(|| {
+            levels
+                .iter()
+                .map(|&it| db_to_amplitude_fast(black_box(it)))
+                .sum::<f32>()
+        })
+    });
+    c.bench_function("block_peak_db", |b| {
+        b.iter(|| block_peak_db(black_box(&amplitudes)))
+    });
+}
+
+criterion_group!(benches, conversions);
+criterion_main!(benches);
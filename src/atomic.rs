@@ -0,0 +1,54 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// An `f32` level that can be shared between threads, e.g. a plugin parameter read by the audio
+/// thread and set by a [`crate::Fader::atomic`] in the GUI.
+///
+/// Stored as the bits of the float in an [`AtomicU32`], loads and stores use relaxed ordering.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use std::sync::Arc;
+/// let gain = Arc::new(egui_fader::AtomicLevel::new(-6.0));
+/// let audio_gain = Arc::clone(&gain);
+/// ui.add(egui_fader::Fader::atomic(&gain, -20.0));
+/// let level = audio_gain.load();
+/// # });
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicLevel(AtomicU32);
+
+impl AtomicLevel {
+    /// Creates a shared level starting at `level`.
+    pub fn new(level: f32) -> Self {
+        Self(AtomicU32::new(level.to_bits()))
+    }
+
+    /// The current level.
+    pub fn load(&self) -> f32 {
+        f32::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    /// Set the level.
+    pub fn store(&self, level: f32) {
+        self.0.store(level.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl From<f32> for AtomicLevel {
+    fn from(level: f32) -> Self {
+        Self::new(level)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn atomic_level_keeps_neg_infinity() {
+        let level = AtomicLevel::new(-6.5);
+        assert_eq!(level.load(), -6.5);
+        level.store(f32::NEG_INFINITY);
+        assert_eq!(level.load(), f32::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,183 @@
+use egui::{Align2, FontId, Rect, Response, Sense, Stroke, Ui, Widget, lerp, pos2, vec2};
+
+use crate::FaderStyle;
+
+/// A small indicator of which side of a stereo signal is louder and by how much.
+///
+/// A needle moves from the centre towards the louder side, reaching the end at `range` dB of
+/// difference, with the difference shown as e.g. `L 3.5` underneath. Signal levels are in dB,
+/// as given to [`crate::Fader::stereo`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let my_signal = [-12.0, -15.5];
+/// ui.add(egui_fader::BalanceMeter::new(my_signal));
+/// # });
+/// ```
+pub struct BalanceMeter {
+    signal: [f32; 2],
+    range: f32,
+    width: Option<f32>,
+    show_value: bool,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl BalanceMeter {
+    /// Creates a balance meter for the `[left, right]` signal levels.
+    pub fn new(signal: [f32; 2]) -> Self {
+        Self {
+            signal,
+            range: 12.0,
+            width: None,
+            show_value: true,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the difference in dB shown at the ends of the meter. Default: `12.0`.
+    #[inline]
+    pub fn range(mut self, range: f32) -> Self {
+        self.range = range.abs();
+        self
+    }
+
+    /// Set the width of the meter in points.
+    /// The default is `egui::Ui.spacing().interact_size.x`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Show the difference underneath the meter. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the meter. The `channel` colour is the background, `signal` the
+    /// needle and `readout_text` the difference.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn meter_ui(&self, ui: &Ui, rect: Rect, difference: f32) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let background = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        ui.painter().rect_filled(rect, corner, background);
+        ui.painter().vline(
+            rect.center().x,
+            rect.y_range(),
+            visuals.widgets.noninteractive.bg_stroke,
+        );
+
+        let position = needle_position(difference, self.range);
+        let x = lerp(rect.x_range(), position);
+        let needle = self
+            .style
+            .signal
+            .unwrap_or(visuals.widgets.active.fg_stroke.color);
+        ui.painter()
+            .vline(x, rect.y_range(), Stroke::new(2.0, needle));
+    }
+}
+
+impl Widget for BalanceMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let width = self.width.unwrap_or_else(|| ui.spacing().interact_size.x);
+        let meter_height = 0.5 * self.text_size;
+        let text_height = if self.show_value {
+            self.text_size * 1.25
+        } else {
+            0.0
+        };
+        let size = vec2(width, meter_height + text_height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let difference = balance_difference(self.signal);
+            let meter_rect = Rect::from_min_size(rect.min, vec2(width, meter_height));
+            self.meter_ui(ui, meter_rect, difference);
+            if self.show_value {
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    pos2(rect.center().x, rect.bottom()),
+                    Align2::CENTER_BOTTOM,
+                    balance_text(difference),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        response
+    }
+}
+
+/// How many dB louder the right side is than the left, `0.0` when both are silent.
+fn balance_difference([left, right]: [f32; 2]) -> f32 {
+    match (left == f32::NEG_INFINITY, right == f32::NEG_INFINITY) {
+        (true, true) => 0.0,
+        (true, false) => f32::INFINITY,
+        (false, true) => f32::NEG_INFINITY,
+        (false, false) => right - left,
+    }
+}
+
+/// Normalised needle position, `0.5` in the centre and `0.0` when the left is `range` louder.
+fn needle_position(difference: f32, range: f32) -> f32 {
+    if range == 0.0 {
+        return 0.5;
+    }
+    (0.5 + 0.5 * difference / range).clamp(0.0, 1.0)
+}
+
+fn balance_text(difference: f32) -> String {
+    let side = if difference > 0.0 { "R" } else { "L" };
+    if difference.abs() < 0.05 {
+        "C".to_owned()
+    } else if difference.is_infinite() {
+        format!("{side} ∞")
+    } else {
+        format!("{side} {:.1}", difference.abs())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn needle_moves_to_the_louder_side() {
+        assert_eq!(needle_position(balance_difference([-6.0, -6.0]), 12.0), 0.5);
+        assert_eq!(
+            needle_position(balance_difference([-6.0, -12.0]), 12.0),
+            0.25
+        );
+        assert_eq!(
+            needle_position(balance_difference([f32::NEG_INFINITY, -20.0]), 12.0),
+            1.0
+        );
+    }
+
+    #[test]
+    fn balance_text_names_the_louder_side() {
+        assert_eq!(balance_text(0.0), "C");
+        assert_eq!(balance_text(-3.5), "L 3.5");
+        assert_eq!(balance_text(f32::INFINITY), "R ∞");
+    }
+}
@@ -0,0 +1,244 @@
+use egui::{
+    Align2, Color32, FontId, Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType, epaint,
+};
+
+use crate::FaderStyle;
+
+/// A mute toggle styled to match a [`crate::Fader`].
+///
+/// It lights up in the same colour as the muted badge of the fader, so pass the same value to
+/// [`crate::Fader::muted`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let (mut my_level, mut my_mute): (f32, bool) = (-10.0, false);
+/// ui.add(egui_fader::MuteButton::new(&mut my_mute));
+/// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).muted(my_mute));
+/// # });
+/// ```
+pub struct MuteButton<'a> {
+    mute: &'a mut bool,
+    text: String,
+    size: Option<Vec2>,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> MuteButton<'a> {
+    /// Creates a mute button toggling `mute`.
+    pub fn new(mute: &'a mut bool) -> Self {
+        Self {
+            mute,
+            text: "M".to_owned(),
+            size: None,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the text on the button. Default: `"M"`.
+    #[inline]
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Set the size of the button in points.
+    /// The default is a square of `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the size of the text on the button.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the button. The `mute` colour is used while on.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for MuteButton<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let on_colour = self.style.mute.unwrap_or(ui.visuals().warn_fg_color);
+        toggle_button_ui(
+            ui,
+            self.mute,
+            &self.text,
+            self.size,
+            self.text_size,
+            on_colour,
+        )
+    }
+}
+
+/// A solo toggle styled to match a [`crate::Fader`].
+///
+/// Use [`SoloButton::exclusive`] for exclusive solo and [`SoloButton::audible`] to decide which
+/// channels to hear.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut solos = [false; 4];
+/// for index in 0..solos.len() {
+///     if ui.add(egui_fader::SoloButton::new(&mut solos[index])).clicked()
+///         && ui.input(|input| input.modifiers.command)
+///     {
+///         egui_fader::SoloButton::exclusive(&mut solos, index);
+///     }
+/// }
+/// # });
+/// ```
+pub struct SoloButton<'a> {
+    solo: &'a mut bool,
+    text: String,
+    size: Option<Vec2>,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> SoloButton<'a> {
+    /// Creates a solo button toggling `solo`.
+    pub fn new(solo: &'a mut bool) -> Self {
+        Self {
+            solo,
+            text: "S".to_owned(),
+            size: None,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the text on the button. Default: `"S"`.
+    #[inline]
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Set the size of the button in points.
+    /// The default is a square of `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the size of the text on the button.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the button. The `solo` colour is used while on.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Make the solo at `index` the only one that is on, if it is on.
+    /// Call this after the button at `index` was clicked to get exclusive solo.
+    pub fn exclusive(solos: &mut [bool], index: usize) {
+        if !solos.get(index).copied().unwrap_or_default() {
+            return;
+        }
+        for (other, solo) in solos.iter_mut().enumerate() {
+            *solo = other == index;
+        }
+    }
+
+    /// Whether a channel should be heard: soloed channels are heard, muted channels are
+    /// silent and while anything is soloed only soloed channels are heard.
+    pub fn audible(mute: bool, solo: bool, any_solo: bool) -> bool {
+        if any_solo { solo } else { !mute }
+    }
+}
+
+impl Widget for SoloButton<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let on_colour = self.style.solo.unwrap_or(ui.visuals().selection.bg_fill);
+        toggle_button_ui(
+            ui,
+            self.solo,
+            &self.text,
+            self.size,
+            self.text_size,
+            on_colour,
+        )
+    }
+}
+
+/// A square toggle with the handle's corner radius, filled with `on_colour` while on.
+fn toggle_button_ui(
+    ui: &mut Ui,
+    on: &mut bool,
+    text: &str,
+    size: Option<Vec2>,
+    text_size: f32,
+    on_colour: Color32,
+) -> Response {
+    let size = size.unwrap_or_else(|| Vec2::splat(ui.spacing().interact_size.y));
+    let (rect, mut response) = ui.allocate_exact_size(size, Sense::click());
+    if response.clicked() {
+        *on = !*on;
+        response.mark_changed();
+    }
+    response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, ui.is_enabled(), *on, text));
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact_selectable(&response, *on);
+        let (fill, text_colour) = if *on {
+            (on_colour, ui.visuals().extreme_bg_color)
+        } else {
+            (visuals.weak_bg_fill, visuals.text_color())
+        };
+        ui.painter().rect(
+            rect.expand(visuals.expansion),
+            visuals.corner_radius,
+            fill,
+            visuals.bg_stroke,
+            epaint::StrokeKind::Inside,
+        );
+        ui.painter().text(
+            rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            FontId::proportional(text_size),
+            text_colour,
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exclusive_solo_turns_off_the_others() {
+        let mut solos = [true, true, false];
+        SoloButton::exclusive(&mut solos, 1);
+        assert_eq!(solos, [false, true, false]);
+        // Turning a solo off leaves the others alone.
+        let mut solos = [true, false, true];
+        SoloButton::exclusive(&mut solos, 1);
+        assert_eq!(solos, [true, false, true]);
+    }
+
+    #[test]
+    fn solo_overrides_mute() {
+        assert!(SoloButton::audible(false, false, false));
+        assert!(!SoloButton::audible(true, false, false));
+        assert!(SoloButton::audible(true, true, true));
+        assert!(!SoloButton::audible(false, false, true));
+    }
+}
@@ -0,0 +1,203 @@
+use egui::{Align, Color32, Layout, NumExt, Response, TextStyle, Ui, Widget, vec2};
+
+use crate::{Fader, MuteButton, Pan, SignalKind, SoloButton};
+
+type ConfigureFader<'a> = Box<dyn 'a + FnOnce(Fader<'_>) -> Fader<'_>>;
+
+/// Called with the new solo state when the solo button is clicked.
+type SoloCallback<'a> = Box<dyn 'a + FnMut(bool)>;
+
+/// The values controlled by a [`ChannelStrip`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChannelStripState {
+    /// The fader level.
+    pub level: f32,
+
+    /// The pan from `-1.0` (left) to `1.0` (right).
+    pub pan: f32,
+
+    /// Whether the mute button is on.
+    pub mute: bool,
+
+    /// Whether the solo button is on.
+    pub solo: bool,
+}
+
+impl ChannelStripState {
+    /// Make the channel at `index` the only soloed one, if it is soloed, see
+    /// [`SoloButton::exclusive`]. Call this after [`ChannelStrip::on_solo`] reports a click.
+    pub fn exclusive_solo(states: &mut [Self], index: usize) {
+        if !states.get(index).is_some_and(|state| state.solo) {
+            return;
+        }
+        for (other, state) in states.iter_mut().enumerate() {
+            state.solo = other == index;
+        }
+    }
+}
+
+/// A mixer channel with a name, pan, mute and solo buttons and a [`Fader`] with a meter.
+///
+/// Solo buttons toggle on their own, use [`Self::on_solo`] with
+/// [`ChannelStripState::exclusive_solo`] for exclusive solo across the mixer.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_channels = vec![egui_fader::ChannelStripState::default(); 4];
+/// let mut soloed = None;
+/// for (index, channel) in my_channels.iter_mut().enumerate() {
+///     ui.add(
+///         egui_fader::ChannelStrip::new(channel, -18.0)
+///             .name(format!("Channel {}", index + 1))
+///             .on_solo(|_| soloed = Some(index))
+///             .configure_fader(|fader| fader.tooltip(true)),
+///     );
+/// }
+/// if let Some(index) = soloed {
+///     egui_fader::ChannelStripState::exclusive_solo(&mut my_channels, index);
+/// }
+/// # });
+/// ```
+pub struct ChannelStrip<'a> {
+    state: &'a mut ChannelStripState,
+    signal: SignalKind,
+    name: String,
+    accent_color: Option<Color32>,
+    show_pan: bool,
+    on_solo: Option<SoloCallback<'a>>,
+    configure_fader: Option<ConfigureFader<'a>>,
+}
+
+impl<'a> ChannelStrip<'a> {
+    /// Creates a channel strip with a mono meter.
+    pub fn new(state: &'a mut ChannelStripState, signal: f32) -> Self {
+        Self::new_inner(state, SignalKind::Mono(signal))
+    }
+
+    /// Creates a channel strip with a stereo meter.
+    pub fn stereo(state: &'a mut ChannelStripState, signal: [f32; 2]) -> Self {
+        Self::new_inner(state, SignalKind::Stereo(signal))
+    }
+
+    fn new_inner(state: &'a mut ChannelStripState, signal: SignalKind) -> Self {
+        Self {
+            state,
+            signal,
+            name: String::new(),
+            accent_color: None,
+            show_pan: true,
+            on_solo: None,
+            configure_fader: None,
+        }
+    }
+
+    /// Set the name shown at the top of the strip.
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Colour code the strip, see [`Fader::accent_color`].
+    #[inline]
+    pub fn accent_color(mut self, accent_color: Color32) -> Self {
+        self.accent_color = Some(accent_color);
+        self
+    }
+
+    /// Set whether the pan control is shown. Default: `true`.
+    #[inline]
+    pub fn show_pan(mut self, show_pan: bool) -> Self {
+        self.show_pan = show_pan;
+        self
+    }
+
+    /// Call `on_solo` with the new solo state when the solo button is clicked, e.g. to make it
+    /// exclusive with [`ChannelStripState::exclusive_solo`].
+    #[inline]
+    pub fn on_solo(mut self, on_solo: impl 'a + FnMut(bool)) -> Self {
+        self.on_solo = Some(Box::new(on_solo));
+        self
+    }
+
+    /// Customise the fader, e.g. its increments or tooltip.
+    #[inline]
+    pub fn configure_fader(
+        mut self,
+        configure_fader: impl 'a + FnOnce(Fader<'_>) -> Fader<'_>,
+    ) -> Self {
+        self.configure_fader = Some(Box::new(configure_fader));
+        self
+    }
+}
+
+impl Widget for ChannelStrip<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            state,
+            signal,
+            name,
+            accent_color,
+            show_pan,
+            mut on_solo,
+            configure_fader,
+        } = self;
+        // Match the width of the fader.
+        let width = 2.0
+            * ui.text_style_height(&TextStyle::Body)
+                .at_least(ui.spacing().interact_size.x);
+        let layout = Layout::top_down(Align::Center);
+        ui.allocate_ui_with_layout(vec2(width, 0.0), layout, |ui| {
+            let mut response = ui.label(&name);
+            if show_pan {
+                response |= ui.add(Pan::new(&mut state.pan).show_value(false));
+            }
+            ui.horizontal(|ui| {
+                response |= ui.add(MuteButton::new(&mut state.mute));
+                let solo_response = ui.add(SoloButton::new(&mut state.solo));
+                if solo_response.clicked()
+                    && let Some(on_solo) = &mut on_solo
+                {
+                    on_solo(state.solo);
+                }
+                response |= solo_response;
+            });
+            let fader = match signal {
+                SignalKind::Mono(signal) => Fader::mono(&mut state.level, signal),
+                SignalKind::Stereo(signal) => Fader::stereo(&mut state.level, signal),
+            };
+            let fader = fader.muted(state.mute);
+            let fader = match accent_color {
+                Some(accent_color) => fader.accent_color(accent_color),
+                None => fader,
+            };
+            let fader = match configure_fader {
+                Some(configure_fader) => configure_fader(fader),
+                None => fader,
+            };
+            response |= ui.add(fader);
+            response
+        })
+        .inner
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exclusive_solo_clears_the_other_channels() {
+        let mut states = vec![ChannelStripState::default(); 3];
+        states[0].solo = true;
+        states[2].solo = true;
+        ChannelStripState::exclusive_solo(&mut states, 2);
+        let solos: Vec<_> = states.iter().map(|state| state.solo).collect();
+        assert_eq!(solos, [false, false, true]);
+        // Unsoloing a channel leaves the others alone.
+        states[0].solo = true;
+        states[2].solo = false;
+        ChannelStripState::exclusive_solo(&mut states, 2);
+        assert!(states[0].solo);
+    }
+}
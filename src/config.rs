@@ -0,0 +1,312 @@
+use std::fmt;
+
+use egui::emath;
+use egui::emath::OrderedFloat;
+
+use crate::{DEFAULT_INCREMENTS, Fader, FaderStyle, PeakMarker};
+
+/// Why a [`FaderConfig`] can't be used, see [`FaderConfig::check`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// There are fewer than two increments.
+    TooFewIncrements,
+
+    /// The increments aren't finite, unique and in ascending order.
+    UnsortedIncrements,
+
+    /// There isn't one segment weight per pair of increments.
+    SegmentWeightCount,
+
+    /// A segment weight isn't positive.
+    NonPositiveWeight,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooFewIncrements => "there must be at least two increments",
+            Self::UnsortedIncrements => "increments must be finite, unique and in ascending order",
+            Self::SegmentWeightCount => "there must be one segment weight per pair of increments",
+            Self::NonPositiveWeight => "segment weights must be positive",
+        })
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Settings shared by many [`Fader`]s, e.g. every channel of a mixer.
+///
+/// Build the config once and apply it with [`Fader::mono_with_config`],
+/// [`Fader::stereo_with_config`] or [`Fader::config`]. The faders borrow the increments and
+/// weights rather than copying them.
+///
+/// With the `serde` feature the config can be saved and loaded, e.g. from a preferences file.
+/// Missing fields take their default, and a config that fails [`FaderConfig::check`] is an
+/// error rather than a fader that panics or maps levels wrongly.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_levels = [0.0_f32; 48];
+/// let config = egui_fader::FaderConfig {
+///     increments: vec![-60.0, -20.0, -6.0, 0.0, 6.0],
+///     text_size: 9.0,
+///     ..Default::default()
+/// };
+/// ui.horizontal(|ui| {
+///     for level in &mut my_levels {
+///         ui.add(egui_fader::Fader::mono_with_config(level, -20.0, &config));
+///     }
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedConfig"))]
+pub struct FaderConfig {
+    /// See [`Fader::increments`].
+    pub increments: Vec<f32>,
+
+    /// See [`Fader::segment_weights`].
+    pub segment_weights: Option<Vec<f32>>,
+
+    /// See [`Fader::neg_infinity_at_bottom`].
+    pub neg_infinity_at_bottom: bool,
+
+    /// See [`Fader::neutral_level`].
+    pub neutral_level: f32,
+
+    /// See [`Fader::text_size`].
+    pub text_size: f32,
+
+    /// See [`Fader::peak_buffer_size`].
+    pub peak_buffer_size: usize,
+
+    /// See [`Fader::peak_marker`].
+    pub peak_marker: PeakMarker,
+
+    /// See [`Fader::peak_thickness`].
+    pub peak_thickness: f32,
+
+    /// See [`Fader::style`].
+    pub style: FaderStyle,
+}
+
+impl Default for FaderConfig {
+    fn default() -> Self {
+        Self {
+            increments: DEFAULT_INCREMENTS.to_vec(),
+            segment_weights: None,
+            neg_infinity_at_bottom: true,
+            neutral_level: 0.0,
+            text_size: 10.0,
+            peak_buffer_size: 60,
+            peak_marker: PeakMarker::default(),
+            peak_thickness: 2.0,
+            style: FaderStyle::default(),
+        }
+    }
+}
+
+impl FaderConfig {
+    /// Check that the increments and segment weights can be used by a fader.
+    pub fn check(&self) -> Result<(), ConfigError> {
+        if self.increments.len() < 2 {
+            return Err(ConfigError::TooFewIncrements);
+        }
+        let ascending = (self
+            .increments
+            .iter()
+            .all(|increment| increment.is_finite()))
+            && (self.increments).is_sorted_by(|a, b| OrderedFloat(*a) < OrderedFloat(*b));
+        if !ascending {
+            return Err(ConfigError::UnsortedIncrements);
+        }
+        if let Some(weights) = &self.segment_weights {
+            if weights.len() + 1 != self.increments.len() {
+                return Err(ConfigError::SegmentWeightCount);
+            }
+            if !weights.iter().all(|weight| *weight > 0.0) {
+                return Err(ConfigError::NonPositiveWeight);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A [`FaderConfig`] as read, before it is checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct UncheckedConfig {
+    increments: Vec<f32>,
+    segment_weights: Option<Vec<f32>>,
+    neg_infinity_at_bottom: bool,
+    neutral_level: f32,
+    text_size: f32,
+    peak_buffer_size: usize,
+    peak_marker: PeakMarker,
+    peak_thickness: f32,
+    style: FaderStyle,
+}
+
+#[cfg(feature = "serde")]
+impl Default for UncheckedConfig {
+    fn default() -> Self {
+        let FaderConfig {
+            increments,
+            segment_weights,
+            neg_infinity_at_bottom,
+            neutral_level,
+            text_size,
+            peak_buffer_size,
+            peak_marker,
+            peak_thickness,
+            style,
+        } = FaderConfig::default();
+        Self {
+            increments,
+            segment_weights,
+            neg_infinity_at_bottom,
+            neutral_level,
+            text_size,
+            peak_buffer_size,
+            peak_marker,
+            peak_thickness,
+            style,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedConfig> for FaderConfig {
+    type Error = ConfigError;
+
+    fn try_from(unchecked: UncheckedConfig) -> Result<Self, ConfigError> {
+        let UncheckedConfig {
+            increments,
+            segment_weights,
+            neg_infinity_at_bottom,
+            neutral_level,
+            text_size,
+            peak_buffer_size,
+            peak_marker,
+            peak_thickness,
+            style,
+        } = unchecked;
+        let config = Self {
+            increments,
+            segment_weights,
+            neg_infinity_at_bottom,
+            neutral_level,
+            text_size,
+            peak_buffer_size,
+            peak_marker,
+            peak_thickness,
+            style,
+        };
+        config.check()?;
+        Ok(config)
+    }
+}
+
+impl<'a> Fader<'a> {
+    /// Creates a fader with only one channel, set up from `config`.
+    pub fn mono_with_config<Num: emath::Numeric>(
+        level: &'a mut Num,
+        signal: f32,
+        config: &'a FaderConfig,
+    ) -> Self {
+        Self::mono(level, signal).config(config)
+    }
+
+    /// Creates a fader with two channels, set up from `config`.
+    pub fn stereo_with_config<Num: emath::Numeric>(
+        level: &'a mut Num,
+        signal: [f32; 2],
+        config: &'a FaderConfig,
+    ) -> Self {
+        Self::stereo(level, signal).config(config)
+    }
+
+    /// Apply the settings in `config`, replacing any set before.
+    #[inline]
+    pub fn config(self, config: &'a FaderConfig) -> Self {
+        let fader = self
+            .increments(&config.increments[..])
+            .neg_infinity_at_bottom(config.neg_infinity_at_bottom)
+            .neutral_level(config.neutral_level)
+            .text_size(config.text_size)
+            .peak_buffer_size(config.peak_buffer_size)
+            .peak_marker(config.peak_marker)
+            .peak_thickness(config.peak_thickness)
+            .style(config.style.clone());
+        match &config.segment_weights {
+            Some(segment_weights) => fader.segment_weights(&segment_weights[..]),
+            None => fader,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_config_matches_fader_defaults() {
+        let config = FaderConfig::default();
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0);
+        assert_eq!(config.increments, &fader.increments[..]);
+        assert_eq!(config.text_size, fader.text_size);
+        assert_eq!(config.peak_buffer_size, fader.peak_buffer_size);
+        assert_eq!(config.peak_thickness, fader.peak_thickness);
+    }
+
+    #[test]
+    fn bad_increments_and_weights_fail_the_check() {
+        assert_eq!(FaderConfig::default().check(), Ok(()));
+        let check = |increments: Vec<f32>, segment_weights: Option<Vec<f32>>| {
+            let config = FaderConfig {
+                increments,
+                segment_weights,
+                ..Default::default()
+            };
+            config.check()
+        };
+        assert_eq!(check(vec![], None), Err(ConfigError::TooFewIncrements));
+        assert_eq!(
+            check(vec![0.0, -6.0], None),
+            Err(ConfigError::UnsortedIncrements)
+        );
+        assert_eq!(
+            check(vec![-6.0, f32::NAN], None),
+            Err(ConfigError::UnsortedIncrements)
+        );
+        assert_eq!(
+            check(vec![-6.0, 0.0], Some(vec![1.0, 1.0])),
+            Err(ConfigError::SegmentWeightCount)
+        );
+        assert_eq!(
+            check(vec![-6.0, 0.0], Some(vec![0.0])),
+            Err(ConfigError::NonPositiveWeight)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn config_round_trips_and_is_checked_on_load() {
+        let config = FaderConfig {
+            increments: vec![-60.0, -20.0, 0.0, 6.0],
+            segment_weights: Some(vec![1.0, 2.0, 1.0]),
+            text_size: 9.0,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<FaderConfig>(&json).unwrap(), config);
+        let partial: FaderConfig = serde_json::from_str(r#"{"text_size": 12.0}"#).unwrap();
+        assert_eq!(partial.increments, DEFAULT_INCREMENTS);
+        assert!(serde_json::from_str::<FaderConfig>(r#"{"increments": []}"#).is_err());
+        let unmatched = r#"{"increments": [-6.0, 0.0], "segment_weights": [1.0, 1.0]}"#;
+        assert!(serde_json::from_str::<FaderConfig>(unmatched).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+use std::f32::consts::FRAC_PI_2;
+
+use egui::{
+    Align2, FontId, Modifiers, Rect, Response, Sense, Ui, Vec2, Widget, epaint, lerp, pos2, vec2,
+};
+
+use crate::knob::drag_normalised;
+use crate::{FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, any_modifier_held};
+
+/// Positions within this distance of the centre snap to it while dragging.
+const CROSSFADER_DETENT: f32 = 0.02;
+
+/// How a [`Crossfader`] position is turned into the gains of the A and B sides.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CrossfadeCurve {
+    /// Gains change linearly, dipping 6 dB at the centre.
+    Linear,
+
+    /// Gains follow a sine/cosine law, keeping the total power constant across the travel.
+    #[default]
+    ConstantPower,
+}
+
+impl CrossfadeCurve {
+    /// The linear gains `[a, b]` for a position from `0.0` (all A) to `1.0` (all B).
+    pub fn gains(self, position: f32) -> [f32; 2] {
+        let position = position.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => [1.0 - position, position],
+            Self::ConstantPower => {
+                let angle = position * FRAC_PI_2;
+                [angle.cos(), angle.sin()]
+            }
+        }
+    }
+}
+
+/// A horizontal crossfader between an A side at `0.0` and a B side at `1.0`.
+///
+/// The A and B labels at either end are dimmed by the gain of their side, use
+/// [`CrossfadeCurve::gains`] to apply the same curve to the audio. Dragging snaps to the centre
+/// when close to it and double clicking returns to the centre.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_position: f32 = 0.5;
+/// let curve = egui_fader::CrossfadeCurve::ConstantPower;
+/// ui.add(egui_fader::Crossfader::new(&mut my_position).curve(curve));
+/// let [gain_a, gain_b] = curve.gains(my_position);
+/// # });
+/// ```
+pub struct Crossfader<'a> {
+    position: &'a mut f32,
+    curve: CrossfadeCurve,
+    detent: bool,
+    labels: [String; 2],
+    width: Option<f32>,
+    handle_size: Option<f32>,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> Crossfader<'a> {
+    /// Creates a crossfader, positions are clamped to `0.0..=1.0`.
+    pub fn new(position: &'a mut f32) -> Self {
+        Self {
+            position,
+            curve: CrossfadeCurve::default(),
+            detent: true,
+            labels: ["A".to_owned(), "B".to_owned()],
+            width: None,
+            handle_size: None,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the crossfade curve used to dim the end labels.
+    #[inline]
+    pub fn curve(mut self, curve: CrossfadeCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Set whether dragging snaps to the centre when close to it. Default: `true`.
+    #[inline]
+    pub fn detent(mut self, detent: bool) -> Self {
+        self.detent = detent;
+        self
+    }
+
+    /// Set the labels at the A and B ends. Default: `"A"` and `"B"`.
+    #[inline]
+    pub fn labels(mut self, a: impl ToString, b: impl ToString) -> Self {
+        self.labels = [a.to_string(), b.to_string()];
+        self
+    }
+
+    /// Set the width of the crossfader in points.
+    /// The default is 1.5 times `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height of the handle in points, its width is half the height.
+    #[inline]
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = Some(handle_size);
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the crossfader.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn interaction(&mut self, ui: &Ui, response: &Response, travel: f32) {
+        if response.double_clicked() {
+            *self.position = 0.5;
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        };
+        let delta = response.drag_delta().x * ratio / travel;
+        if let Some(position) = drag_normalised(ui, response, *self.position, delta) {
+            *self.position = snap_to_centre(position, self.detent);
+        }
+    }
+
+    fn crossfader_ui(&self, ui: &Ui, response: &Response, rail_rect: Rect, handle: Vec2) {
+        let visuals = ui.style().interact(response);
+        let rail_height = ui.spacing().slider_rail_height;
+        let rail = Rect::from_center_size(rail_rect.center(), vec2(rail_rect.width(), rail_height));
+        let rail_colour = self
+            .style
+            .rail
+            .unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        let rail_corner = self
+            .style
+            .rail_corner_radius
+            .unwrap_or(ui.visuals().widgets.inactive.corner_radius);
+        ui.painter().rect_filled(rail, rail_corner, rail_colour);
+
+        // Centre mark.
+        let centre_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        ui.painter()
+            .vline(rail_rect.center().x, rail_rect.y_range(), centre_stroke);
+
+        let x = lerp(
+            rail_rect.left() + 0.5 * handle.x..=rail_rect.right() - 0.5 * handle.x,
+            *self.position,
+        );
+        let handle_rect =
+            Rect::from_center_size(pos2(x, rail_rect.center().y), handle).expand(visuals.expansion);
+        ui.painter().rect(
+            handle_rect,
+            visuals.corner_radius,
+            self.style.handle_fill.unwrap_or(visuals.bg_fill),
+            self.style.handle_stroke.unwrap_or(visuals.fg_stroke),
+            epaint::StrokeKind::Inside,
+        );
+    }
+
+    fn labels_ui(&self, ui: &Ui, rect: Rect) {
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        let gains = self.curve.gains(*self.position);
+        let anchors = [Align2::LEFT_BOTTOM, Align2::RIGHT_BOTTOM];
+        let positions = [rect.left_bottom(), rect.right_bottom()];
+        for (((label, gain), anchor), pos) in
+            self.labels.iter().zip(gains).zip(anchors).zip(positions)
+        {
+            // Keep the label readable when its side is silent.
+            let colour = text_colour.gamma_multiply(lerp(0.3..=1.0, gain));
+            ui.painter().text(
+                pos,
+                anchor,
+                label,
+                FontId::proportional(self.text_size),
+                colour,
+            );
+        }
+    }
+}
+
+impl Widget for Crossfader<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_position = *self.position;
+        // An out of range position is written back clamped, and reported as a change.
+        *self.position = self.position.clamp(0.0, 1.0);
+        let width = self
+            .width
+            .unwrap_or_else(|| 1.5 * ui.spacing().slider_width);
+        let handle_height = self
+            .handle_size
+            .unwrap_or_else(|| ui.spacing().interact_size.y);
+        let handle = vec2(0.5 * handle_height, handle_height);
+        let text_height = self.text_size * 1.25;
+        let size = vec2(width, handle_height + text_height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        self.interaction(ui, &response, (width - handle.x).max(1.0));
+        if ui.is_rect_visible(rect) {
+            let (rail_rect, label_rect) = rect.split_top_bottom_at_y(rect.top() + handle_height);
+            self.crossfader_ui(ui, &response, rail_rect, handle);
+            self.labels_ui(ui, label_rect);
+        }
+        if *self.position != old_position {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+fn snap_to_centre(position: f32, detent: bool) -> f32 {
+    if detent && (position - 0.5).abs() < CROSSFADER_DETENT {
+        0.5
+    } else {
+        position
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constant_power_keeps_power_across_travel() {
+        for position in [0.0, 0.25, 0.5, 0.9, 1.0] {
+            let [a, b] = CrossfadeCurve::ConstantPower.gains(position);
+            assert!((a * a + b * b - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(CrossfadeCurve::Linear.gains(0.25), [0.75, 0.25]);
+        assert_eq!(CrossfadeCurve::Linear.gains(2.0), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn crossfader_snaps_to_centre_with_detent() {
+        assert_eq!(snap_to_centre(0.51, true), 0.5);
+        assert_eq!(snap_to_centre(0.51, false), 0.51);
+    }
+
+    #[test]
+    fn out_of_range_position_is_clamped_as_a_change() {
+        let ctx = egui::Context::default();
+        let mut position = -0.5;
+        let mut changed = Vec::new();
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    changed.push(ui.add(Crossfader::new(&mut position)).changed());
+                });
+            });
+        }
+        assert_eq!(position, 0.0);
+        assert_eq!(changed, [true, false]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::INFINITY;
+
+/// Convert a linear amplitude to dB, e.g. `0.5` to about `-6.02`. The sign is ignored and
+/// silence is `NEG_INFINITY`, the bottom of a [`crate::Fader`].
+pub fn amplitude_to_db(amplitude: f32) -> f32 {
+    20.0 * amplitude.abs().log10()
+}
+
+/// Convert a level in dB to a linear amplitude, e.g. `-6.0` to about `0.5`.
+/// `NEG_INFINITY` is silence.
+pub fn db_to_amplitude(db: f32) -> f32 {
+    10_f32.powf(db / 20.0)
+}
+
+/// The peak of a block of samples in dB, e.g. to feed a meter once per audio callback.
+pub fn block_peak_db(samples: &[f32]) -> f32 {
+    let peak = samples
+        .iter()
+        .fold(0.0_f32, |peak, sample| peak.max(sample.abs()));
+    amplitude_to_db(peak)
+}
+
+/// Like [`amplitude_to_db`] but approximated from the bits of the float, within 0.002 dB.
+/// About three times as fast as `log10`, for hot paths where that error doesn't show on a meter.
+/// See `benches/db.rs`.
+pub fn amplitude_to_db_fast(amplitude: f32) -> f32 {
+    let amplitude = amplitude.abs();
+    if !amplitude.is_normal() {
+        // Zero, subnormals, infinity and NaN.
+        return amplitude_to_db(amplitude);
+    }
+    // 20 * log10(2)
+    const DB_PER_OCTAVE: f32 = 6.020_6;
+    let bits = amplitude.to_bits();
+    let exponent = ((bits >> 23) & 0xff) as i32 - 127;
+    // The mantissa in [1, 2).
+    let m = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    let log2_mantissa =
+        -2.496_806 + m * (4.028_45 + m * (-2.081_128 + m * (0.628_841 + m * -0.079_154)));
+    DB_PER_OCTAVE * (exponent as f32 + log2_mantissa)
+}
+
+/// Like [`db_to_amplitude`] but approximated with a polynomial, within 0.002 dB.
+/// The gain over `powf` is smaller than for [`amplitude_to_db_fast`].
+pub fn db_to_amplitude_fast(db: f32) -> f32 {
+    // log2(10) / 20
+    const OCTAVES_PER_DB: f32 = 0.166_096_4;
+    let octaves = db * OCTAVES_PER_DB;
+    if !(-126.0..128.0).contains(&octaves) {
+        // Out of the range of normal floats, or NaN.
+        return if db == -INFINITY || octaves < 0.0 {
+            0.0
+        } else {
+            db_to_amplitude(db)
+        };
+    }
+    // Round towards negative infinity without calling `floor`.
+    let whole = octaves as i32;
+    let whole = whole - (whole as f32 > octaves) as i32;
+    let f = octaves - whole as f32;
+    let exp2_fraction = 0.999_812 + f * (0.696_838 + f * (0.224_127 + f * 0.079_020));
+    f32::from_bits(((whole + 127) as u32) << 23) * exp2_fraction
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversions_round_trip() {
+        assert_eq!(amplitude_to_db(1.0), 0.0);
+        assert_eq!(amplitude_to_db(0.0), -INFINITY);
+        assert_eq!(db_to_amplitude(-INFINITY), 0.0);
+        assert!((amplitude_to_db(-0.5) + 6.0206).abs() < 1e-4);
+        assert!((db_to_amplitude(-6.0206) - 0.5).abs() < 1e-5);
+        assert!((block_peak_db(&[0.1, -0.5, 0.25]) + 6.0206).abs() < 1e-4);
+        assert_eq!(block_peak_db(&[]), -INFINITY);
+    }
+
+    #[test]
+    fn fast_conversions_are_close() {
+        for step in 0..=2000 {
+            let db = -150.0 + step as f32 * 0.1;
+            let amplitude = db_to_amplitude(db);
+            assert!((amplitude_to_db_fast(amplitude) - db).abs() < 0.002, "{db}");
+            let fast = db_to_amplitude_fast(db);
+            assert!((amplitude_to_db(fast) - db).abs() < 0.002, "{db}");
+        }
+        assert_eq!(amplitude_to_db_fast(0.0), -INFINITY);
+        assert_eq!(db_to_amplitude_fast(-INFINITY), 0.0);
+    }
+}
@@ -0,0 +1,310 @@
+use std::borrow::Cow;
+
+use egui::{
+    Align, Align2, CursorIcon, Id, Key, Modifiers, Response, Sense, TextEdit, TextStyle, Ui,
+    Widget, emath, epaint, vec2,
+};
+
+use crate::knob::drag_normalised;
+use crate::{
+    DEFAULT_INCREMENTS, FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, GetSetValue, INFINITY,
+    any_modifier_held, get, normalised_from_value, set, value_from_normalised,
+};
+
+/// A numeric drag field with the same increments and taper as a [`crate::Fader`].
+///
+/// Dragging right or up raises the value, moving through the scale at the same speed as a
+/// fader would, so there is more resolution around 0 and `-∞` is at the bottom. Click to type
+/// a value, Enter commits it and escape cancels. Useful in property panels where a whole fader
+/// is too much.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_send: f32 = -10.0;
+/// ui.horizontal(|ui| {
+///     ui.label("Send");
+///     ui.add(egui_fader::DbDragValue::new(&mut my_send).suffix(" dB"));
+/// });
+/// # });
+/// ```
+pub struct DbDragValue<'a> {
+    get_set_value: GetSetValue<'a>,
+    integral: bool,
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    neg_infinity_at_bottom: bool,
+    drag_distance: f32,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    decimals: usize,
+    suffix: String,
+    infinity_text: String,
+}
+
+impl<'a> DbDragValue<'a> {
+    /// Creates a drag value controlling `level`.
+    pub fn new<Num: emath::Numeric>(level: &'a mut Num) -> Self {
+        let get_set_value = move |v: Option<f64>| {
+            if let Some(v) = v {
+                *level = Num::from_f64(v);
+            }
+            level.to_f64()
+        };
+        Self {
+            get_set_value: Box::new(get_set_value),
+            integral: Num::INTEGRAL,
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            neg_infinity_at_bottom: true,
+            drag_distance: 300.0,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            decimals: 1,
+            suffix: String::new(),
+            infinity_text: "-∞".to_owned(),
+        }
+    }
+
+    /// Set the increments that make up the range, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set a relative weight for each segment between increments, see
+    /// [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set whether the bottom of the range sets the level to `NEG_INFINITY`.
+    /// Default: `true`.
+    #[inline]
+    pub fn neg_infinity_at_bottom(mut self, neg_infinity_at_bottom: bool) -> Self {
+        self.neg_infinity_at_bottom = neg_infinity_at_bottom;
+        self
+    }
+
+    /// Set how far the pointer must be dragged to go through the whole range.
+    /// Default: `300.0` points.
+    #[inline]
+    pub fn drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Set the number of decimals shown. Default: `1`.
+    #[inline]
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set a unit shown after the value, e.g. `" dB"`. It is ignored when typing a value.
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Set the text shown for `NEG_INFINITY`. Default: `"-∞"`.
+    #[inline]
+    pub fn infinity_text(mut self, infinity_text: impl ToString) -> Self {
+        self.infinity_text = infinity_text.to_string();
+        self
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        normalised_from_value(value, &self.increments, self.segment_weights.as_deref())
+    }
+
+    fn denormalise(&self, normalised: f32) -> f32 {
+        let value = value_from_normalised(
+            normalised,
+            &self.increments,
+            self.segment_weights.as_deref(),
+        );
+        if value == -INFINITY && !self.neg_infinity_at_bottom {
+            self.increments[0]
+        } else {
+            value
+        }
+    }
+
+    fn get_level(&mut self) -> f64 {
+        get(&mut self.get_set_value)
+    }
+
+    fn set_level(&mut self, level: f64) {
+        let level = if self.integral {
+            level.max(self.increments[0] as f64).round()
+        } else {
+            level
+        };
+        set(&mut self.get_set_value, level)
+    }
+
+    fn level_text(&self, level: f64) -> String {
+        let text = if level == f64::NEG_INFINITY {
+            self.infinity_text.clone()
+        } else {
+            let decimals = self.decimals;
+            format!("{level:.decimals$}")
+        };
+        format!("{text}{}", self.suffix)
+    }
+
+    /// Parse typed text into a level. Values outside the range are clamped.
+    fn parse_level(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        let text = text.strip_suffix(self.suffix.trim()).unwrap_or(text).trim();
+        let value = match text {
+            "-inf" | "-∞" | "−∞" => -INFINITY,
+            _ if text == self.infinity_text.trim() => -INFINITY,
+            _ => text.parse::<f32>().ok()?,
+        };
+        let min = self.increments[0];
+        let max = self.increments[self.increments.len() - 1];
+        let value = if value < min {
+            self.denormalise(0.0)
+        } else {
+            value.min(max)
+        };
+        Some(value as f64)
+    }
+
+    fn drag_interaction(&mut self, ui: &Ui, response: &Response) {
+        let modifiers = ui.input(|input| input.modifiers);
+        let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        };
+        let delta = response.drag_delta();
+        let delta = (delta.x - delta.y) * ratio / self.drag_distance;
+        let level = self.get_level() as f32;
+        let normalised = self.normalise(level);
+        if let Some(normalised) = drag_normalised(ui, response, normalised, delta) {
+            self.set_level(self.denormalise(normalised) as f64);
+        }
+    }
+
+    /// Swap the field for a text edit while typing a value.
+    /// Returns the response of the text edit while editing.
+    fn value_entry_ui(&mut self, ui: &mut Ui, id: Id) -> Option<Response> {
+        let edit_id = id.with("edit");
+        let mut text = ui.data(|data| data.get_temp::<String>(edit_id))?;
+        let edit_response = ui.add(
+            TextEdit::singleline(&mut text)
+                .id(edit_id)
+                .desired_width(ui.spacing().interact_size.x)
+                .horizontal_align(Align::Center),
+        );
+        if edit_response.lost_focus() {
+            if ui.input(|input| input.key_pressed(Key::Enter))
+                && let Some(level) = self.parse_level(&text)
+            {
+                self.set_level(level);
+            }
+            ui.data_mut(|data| data.remove::<String>(edit_id));
+        } else {
+            ui.data_mut(|data| data.insert_temp(edit_id, text));
+        }
+        Some(edit_response)
+    }
+}
+
+impl Widget for DbDragValue<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let id = ui.next_auto_id();
+        let old_level = self.get_level();
+        if let Some(mut response) = self.value_entry_ui(ui, id) {
+            if self.get_level() != old_level {
+                response.mark_changed();
+            }
+            return response;
+        }
+
+        let text = self.level_text(old_level);
+        let font_id = TextStyle::Button.resolve(ui.style());
+        let galley = ui
+            .painter()
+            .layout_no_wrap(text, font_id, ui.visuals().text_color());
+        let padding = ui.spacing().button_padding;
+        let size = vec2(
+            (galley.size().x + 2.0 * padding.x).max(ui.spacing().interact_size.x),
+            ui.spacing().interact_size.y,
+        );
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        self.drag_interaction(ui, &response);
+        if response.clicked() {
+            let edit_id = id.with("edit");
+            let level = self.get_level();
+            let text = self.level_text(level);
+            ui.data_mut(|data| data.insert_temp(edit_id, text));
+            ui.memory_mut(|mem| mem.request_focus(edit_id));
+        }
+        if response.hovered() || response.dragged() {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeHorizontal);
+        }
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            ui.painter().rect(
+                rect.expand(visuals.expansion),
+                visuals.corner_radius,
+                visuals.weak_bg_fill,
+                visuals.bg_stroke,
+                epaint::StrokeKind::Inside,
+            );
+            // Show the new level while dragging rather than the one laid out above.
+            let level = self.get_level();
+            let text = self.level_text(level);
+            let font_id = TextStyle::Button.resolve(ui.style());
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                text,
+                font_id,
+                visuals.text_color(),
+            );
+        }
+        if self.get_level() != old_level {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn drag_value_text_round_trips() {
+        let mut level: f32 = 0.0;
+        let drag_value = DbDragValue::new(&mut level).suffix(" dB");
+        assert_eq!(drag_value.level_text(-6.0), "-6.0 dB");
+        assert_eq!(drag_value.level_text(f64::NEG_INFINITY), "-∞ dB");
+        assert_eq!(drag_value.parse_level("-6.0 dB"), Some(-6.0));
+        assert_eq!(drag_value.parse_level("-∞ dB"), Some(f64::NEG_INFINITY));
+        assert_eq!(drag_value.parse_level("-200"), Some(f64::NEG_INFINITY));
+        assert_eq!(drag_value.parse_level("20"), Some(10.0));
+    }
+}
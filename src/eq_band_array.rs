@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+
+use egui::{
+    Align2, FontId, Modifiers, Rangef, Rect, Response, Sense, Ui, Widget, epaint, lerp, pos2, vec2,
+};
+
+use crate::knob::drag_normalised;
+use crate::{
+    FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, INFINITY, any_modifier_held,
+    normalised_from_value, value_from_normalised,
+};
+
+const EQ_INCREMENTS: [f32; 5] = [-12.0, -6.0, 0.0, 6.0, 12.0];
+
+/// The bands of a graphic EQ: skinny bipolar faders with their frequencies underneath and one
+/// dB scale on the left, writing into a slice of gains.
+///
+/// Each band fills from 0 dB to its handle, dragging moves one band and double clicking returns
+/// it to 0 dB.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let frequencies = [31.0, 63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0];
+/// # let mut my_gains = [0.0; 10];
+/// ui.add(egui_fader::EqBandArray::new(&mut my_gains, &frequencies));
+/// # });
+/// ```
+pub struct EqBandArray<'a> {
+    gains: &'a mut [f32],
+    frequencies: &'a [f32],
+    increments: Cow<'a, [f32]>,
+    height: Option<f32>,
+    band_width: f32,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> EqBandArray<'a> {
+    /// Creates an EQ with one band per gain, labelled with the frequencies in Hz.
+    pub fn new(gains: &'a mut [f32], frequencies: &'a [f32]) -> Self {
+        Self {
+            gains,
+            frequencies,
+            increments: Cow::Borrowed(&EQ_INCREMENTS),
+            height: None,
+            band_width: 16.0,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the dB scale, see [`crate::Fader::increments`].
+    /// Default: `[-12, -6, 0, 6, 12]`.
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set the height of the faders in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width given to each band in points. Default: `16.0`.
+    #[inline]
+    pub fn band_width(mut self, band_width: f32) -> Self {
+        self.band_width = band_width;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Set the size of the scale and frequency labels.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the bands. The `rail`, `rail_fill`, `handle_fill`, `handle_stroke` and
+    /// `label_text` colours are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, gain: f32) -> f32 {
+        normalised_from_value(gain, &self.increments, None)
+    }
+
+    /// The bands have no `NEG_INFINITY`, the bottom is the first increment.
+    fn denormalise(&self, normalised: f32) -> f32 {
+        let gain = value_from_normalised(normalised, &self.increments, None);
+        if gain == -INFINITY {
+            self.increments[0]
+        } else {
+            gain
+        }
+    }
+
+    fn band_interaction(&mut self, ui: &Ui, response: &Response, index: usize, travel: f32) {
+        if response.double_clicked() {
+            let min = self.increments[0];
+            let max = self.increments[self.increments.len() - 1];
+            self.gains[index] = 0.0_f32.clamp(min, max);
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        };
+        let delta = -response.drag_delta().y * ratio / travel;
+        let normalised = self.normalise(self.gains[index]);
+        if let Some(normalised) = drag_normalised(ui, response, normalised, delta) {
+            self.gains[index] = self.denormalise(normalised);
+        }
+    }
+
+    fn scale_ui(&self, ui: &Ui, rect: Rect, position_range: Rangef) {
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        for &gain in self.increments.iter() {
+            let y = lerp(position_range, self.normalise(gain));
+            ui.painter().text(
+                pos2(rect.right(), y),
+                Align2::RIGHT_CENTER,
+                format!("{gain}"),
+                FontId::proportional(self.text_size),
+                text_colour,
+            );
+        }
+    }
+
+    fn band_ui(&self, ui: &Ui, response: &Response, index: usize, position_range: Rangef) {
+        let visuals = ui.style().interact(response);
+        let rect = response.rect;
+        let rail_width = 0.5 * ui.spacing().slider_rail_height;
+        let rail = Rect::from_center_size(rect.center(), vec2(rail_width, rect.height()));
+        let rail_corner = self
+            .style
+            .rail_corner_radius
+            .unwrap_or(ui.visuals().widgets.inactive.corner_radius);
+        let rail_colour = self
+            .style
+            .rail
+            .unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        ui.painter().rect_filled(rail, rail_corner, rail_colour);
+
+        // Fill from 0 dB to the handle.
+        let zero = lerp(position_range, self.normalise(0.0));
+        let y = lerp(position_range, self.normalise(self.gains[index]));
+        let fill = Rect::from_x_y_ranges(rail.x_range(), Rangef::new(zero, y).as_positive());
+        let fill_colour = self
+            .style
+            .rail_fill
+            .unwrap_or(ui.visuals().selection.bg_fill);
+        ui.painter().rect_filled(fill, rail_corner, fill_colour);
+
+        let handle = vec2(0.75 * rect.width(), 0.5 * ui.spacing().interact_size.y);
+        let handle_rect =
+            Rect::from_center_size(pos2(rect.center().x, y), handle).expand(visuals.expansion);
+        ui.painter().rect(
+            handle_rect,
+            visuals.corner_radius,
+            self.style.handle_fill.unwrap_or(visuals.bg_fill),
+            self.style.handle_stroke.unwrap_or(visuals.fg_stroke),
+            epaint::StrokeKind::Inside,
+        );
+    }
+}
+
+impl Widget for EqBandArray<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let scale_width = 2.0 * self.text_size;
+        let text_height = self.text_size * 1.25;
+        let height = self.height.unwrap_or_else(|| ui.spacing().slider_width);
+        let bands = self.gains.len() as f32;
+        let size = vec2(scale_width + bands * self.band_width, height + text_height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::hover());
+
+        let handle_height = 0.5 * ui.spacing().interact_size.y;
+        let faders = Rect::from_min_size(rect.min, vec2(rect.width(), height));
+        let position_range = faders.y_range().shrink(0.5 * handle_height).flip();
+        let travel = position_range.span().abs().max(1.0);
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        for index in 0..self.gains.len() {
+            let left = faders.left() + scale_width + index as f32 * self.band_width;
+            let band_rect = Rect::from_x_y_ranges(left..=left + self.band_width, faders.y_range());
+            let band_response =
+                ui.interact(band_rect, response.id.with(index), Sense::click_and_drag());
+            let old_gain = self.gains[index];
+            self.band_interaction(ui, &band_response, index, travel);
+            if self.gains[index] != old_gain {
+                response.mark_changed();
+            }
+            if ui.is_rect_visible(band_rect) {
+                self.band_ui(ui, &band_response, index, position_range);
+                if let Some(&frequency) = self.frequencies.get(index) {
+                    ui.painter().text(
+                        pos2(band_rect.center().x, rect.bottom()),
+                        Align2::CENTER_BOTTOM,
+                        frequency_text(frequency),
+                        FontId::proportional(self.text_size),
+                        text_colour,
+                    );
+                }
+            }
+        }
+        if ui.is_rect_visible(rect) {
+            let scale = Rect::from_min_size(faders.min, vec2(scale_width, height));
+            self.scale_ui(ui, scale, position_range);
+        }
+        response
+    }
+}
+
+/// A compact frequency label, e.g. `63`, `1k` or `2.5k`.
+fn frequency_text(frequency: f32) -> String {
+    if frequency >= 1000.0 {
+        let kilohertz = frequency / 1000.0;
+        if kilohertz.fract() == 0.0 {
+            format!("{kilohertz}k")
+        } else {
+            format!("{kilohertz:.1}k")
+        }
+    } else {
+        format!("{frequency:.0}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn frequencies_are_compact() {
+        assert_eq!(frequency_text(63.0), "63");
+        assert_eq!(frequency_text(1000.0), "1k");
+        assert_eq!(frequency_text(2500.0), "2.5k");
+        assert_eq!(frequency_text(16000.0), "16k");
+    }
+}
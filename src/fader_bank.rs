@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+
+use egui::{Mesh, Response, Shape, Ui, Widget};
+
+use crate::{DEFAULT_INCREMENTS, Fader, FaderSelection, INFINITY};
+
+type ConfigureFader<'a> = Box<dyn 'a + Fn(usize, Fader<'_>) -> Fader<'_>>;
+
+/// Many mono faders side by side, sharing one column of increment labels on the left.
+///
+/// Each fader drops its own label column, so a bank of 32 channels is much narrower than 32
+/// [`Fader`]s. The increments are borrowed by every fader rather than copied.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut levels = [0.0_f32; 8];
+/// # let signals = [-20.0_f32; 8];
+/// ui.add(
+///     egui_fader::FaderBank::new(&mut levels, &signals)
+///         .configure_fader(|index, fader| fader.tooltip(index == 0)),
+/// );
+/// # });
+/// ```
+pub struct FaderBank<'a> {
+    levels: &'a mut [f32],
+    signals: &'a [f32],
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    height: Option<f32>,
+    configure_fader: Option<ConfigureFader<'a>>,
+    selection: Option<&'a mut FaderSelection>,
+    batched: bool,
+}
+
+impl<'a> FaderBank<'a> {
+    /// Creates a bank with one fader per level. Missing signals show as silence.
+    pub fn new(levels: &'a mut [f32], signals: &'a [f32]) -> Self {
+        Self {
+            levels,
+            signals,
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            height: None,
+            configure_fader: None,
+            selection: None,
+            batched: false,
+        }
+    }
+
+    /// Set the increments of every fader and the shared scale, see [`Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set the segment weights of every fader, see [`Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the height of the faders in points.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Customise each fader, called with the index of the fader.
+    /// The scale column is configured like the first fader so it lines up with the rest, so
+    /// keep the geometry (e.g. the handle size and text size) the same for every fader.
+    #[inline]
+    pub fn configure_fader(
+        mut self,
+        configure_fader: impl 'a + Fn(usize, Fader<'_>) -> Fader<'_>,
+    ) -> Self {
+        self.configure_fader = Some(Box::new(configure_fader));
+        self
+    }
+
+    /// Let faders be selected and dragged together, see [`FaderSelection`].
+    /// The bank applies the drag to the rest of the selection itself.
+    #[inline]
+    pub fn selection(mut self, selection: &'a mut FaderSelection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Paint the rails and meters of every fader into one mesh of square cornered shapes, see
+    /// [`crate::MeterBridge::batched`]. The handles and text are still painted per fader.
+    /// Default: `false`.
+    #[inline]
+    pub fn batched(mut self, batched: bool) -> Self {
+        self.batched = batched;
+        self
+    }
+}
+
+impl Widget for FaderBank<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            levels,
+            signals,
+            increments,
+            segment_weights,
+            height,
+            configure_fader,
+            mut selection,
+            batched,
+        } = self;
+        let weights = segment_weights.as_deref();
+        let configure_fader = configure_fader.as_ref();
+        ui.horizontal_top(|ui| {
+            let mut scale_level = 0.0_f32;
+            let scale = Fader::mono(&mut scale_level, -INFINITY);
+            let scale = configured(0, scale, &increments, weights, height, configure_fader);
+            let mut response = ui.add(scale.labels_only());
+            // Keep a place for the mesh beneath the handles of the faders.
+            let mut mesh = Mesh::default();
+            let mesh_slot = batched.then(|| ui.painter().add(Shape::Noop));
+            for (index, level) in levels.iter_mut().enumerate() {
+                let signal = signals.get(index).copied().unwrap_or(-INFINITY);
+                let fader = Fader::mono(level, signal);
+                let fader = configured(index, fader, &increments, weights, height, configure_fader);
+                let fader = match selection.as_deref_mut() {
+                    Some(selection) => fader.selection(selection, index),
+                    None => fader,
+                };
+                let fader = if batched {
+                    fader.batch(&mut mesh)
+                } else {
+                    fader
+                };
+                response |= ui
+                    .push_id(index, |ui| ui.add(fader.show_labels(false)))
+                    .inner;
+            }
+            if let Some(mesh_slot) = mesh_slot {
+                ui.painter().set(mesh_slot, Shape::mesh(mesh));
+            }
+            if let Some(selection) = selection {
+                selection.apply(levels, &increments, weights);
+            }
+            response
+        })
+        .inner
+    }
+}
+
+/// Apply the settings shared by the whole bank and then the caller's configuration.
+fn configured<'f>(
+    index: usize,
+    fader: Fader<'f>,
+    increments: &'f [f32],
+    segment_weights: Option<&'f [f32]>,
+    height: Option<f32>,
+    configure_fader: Option<&ConfigureFader<'_>>,
+) -> Fader<'f> {
+    let fader = fader.increments(increments);
+    let fader = match segment_weights {
+        Some(segment_weights) => fader.segment_weights(segment_weights),
+        None => fader,
+    };
+    let fader = match height {
+        Some(height) => fader.height(height),
+        None => fader,
+    };
+    match configure_fader {
+        Some(configure_fader) => configure_fader(index, fader),
+        None => fader,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use egui::{Context, Id};
+
+    use super::*;
+
+    #[test]
+    fn batched_faders_share_one_mesh() {
+        let ctx = Context::default();
+        let count_shapes = |batched| {
+            let mut levels = [0.0_f32; 8];
+            let mut run = || {
+                ctx.run(Default::default(), |ctx| {
+                    egui::Area::new(Id::new("bank")).show(ctx, |ui| {
+                        ui.add(FaderBank::new(&mut levels, &[-20.0; 8]).batched(batched));
+                    });
+                })
+            };
+            // Areas are invisible on their first frame while they are sized.
+            run();
+            let output = run();
+            let meshes = (output.shapes.iter())
+                .filter(|shape| matches!(shape.shape, Shape::Mesh(..)))
+                .count();
+            (output.shapes.len(), meshes)
+        };
+        let (unbatched, unbatched_meshes) = count_shapes(false);
+        let (batched, batched_meshes) = count_shapes(true);
+        assert_eq!((unbatched_meshes, batched_meshes), (0, 1));
+        // A rail, channel, signal and peak per fader become part of the mesh.
+        assert_eq!(unbatched - batched, 8 * 4 - 1);
+    }
+}
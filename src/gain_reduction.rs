@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+
+use egui::{Align2, FontId, Rect, Response, Sense, Ui, Widget, pos2, vec2};
+
+use crate::peak::PeakDetector;
+use crate::{FaderStyle, normalised_from_value, reduced_motion};
+
+const GAIN_REDUCTION_INCREMENTS: [f32; 5] = [0.0, 3.0, 6.0, 10.0, 20.0];
+
+/// A meter of the gain reduction of a compressor or limiter, in dB.
+///
+/// The bar grows down from 0 dB at the top with a dB scale beside it, and the deepest recent
+/// reduction is held like the peak of a [`crate::Fader`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let my_gain_reduction = 4.5;
+/// ui.add(egui_fader::GainReductionMeter::new(my_gain_reduction));
+/// # });
+/// ```
+pub struct GainReductionMeter<'a> {
+    gain_reduction: f32,
+    increments: Cow<'a, [f32]>,
+    height: Option<f32>,
+    meter_width: f32,
+    peak_buffer_size: usize,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> GainReductionMeter<'a> {
+    /// Creates a gain reduction meter, `gain_reduction` is in dB and positive when reducing,
+    /// e.g. `6.0` for 6 dB of reduction.
+    pub fn new(gain_reduction: f32) -> Self {
+        Self {
+            gain_reduction: gain_reduction.abs(),
+            increments: Cow::Borrowed(&GAIN_REDUCTION_INCREMENTS),
+            height: None,
+            meter_width: 6.0,
+            peak_buffer_size: 60,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the scale from the top down. Default: `[0, 3, 6, 10, 20]`.
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set the height of the meter in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width of the bar in points. Default: `6.0`.
+    #[inline]
+    pub fn meter_width(mut self, meter_width: f32) -> Self {
+        self.meter_width = meter_width;
+        self
+    }
+
+    /// Set the number of frames the deepest reduction is held for.
+    #[inline]
+    pub fn peak_buffer_size(mut self, peak_buffer_size: usize) -> Self {
+        self.peak_buffer_size = peak_buffer_size;
+        self
+    }
+
+    /// Set the size of the scale labels.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the meter. The `channel`, `signal`, `peak` and `label_text` colours
+    /// are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, gain_reduction: f32) -> f32 {
+        normalised_from_value(gain_reduction, &self.increments, None)
+    }
+
+    fn next_peak(&self, ui: &Ui, response: &Response) -> f32 {
+        let id = response.id.with("peak");
+        let peak_buffer_size = if reduced_motion(ui) {
+            1
+        } else {
+            self.peak_buffer_size
+        };
+        ui.memory_mut(|mem| {
+            let detector = mem
+                .data
+                .get_temp_mut_or_insert_with(id, || PeakDetector::new(peak_buffer_size));
+            if detector.buffer_size() != peak_buffer_size.max(1) {
+                *detector = PeakDetector::new(peak_buffer_size);
+            }
+            detector.next(self.gain_reduction)
+        })
+    }
+
+    fn labels_ui(&self, ui: &Ui, rect: Rect) {
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        let font_id = FontId::proportional(self.text_size);
+        // Keep the end labels inside the widget.
+        let half_text = 0.5 * self.text_size;
+        for &value in self.increments.iter() {
+            let y = rect.top() + rect.height() * self.normalise(value);
+            let y = y.clamp(rect.top() + half_text, rect.bottom() - half_text);
+            ui.painter().text(
+                pos2(rect.right(), y),
+                Align2::RIGHT_CENTER,
+                format!("{value}"),
+                font_id.clone(),
+                text_colour,
+            );
+        }
+    }
+
+    fn meter_ui(&self, ui: &Ui, rect: Rect, peak: f32) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let channel_colour = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        ui.painter().rect_filled(rect, corner, channel_colour);
+
+        let bar_bottom = rect.top() + rect.height() * self.normalise(self.gain_reduction);
+        let bar_rect = Rect::from_x_y_ranges(rect.x_range(), rect.top()..=bar_bottom);
+        let bar_colour = self.style.signal.unwrap_or(visuals.warn_fg_color);
+        ui.painter().rect_filled(bar_rect, corner, bar_colour);
+
+        let peak_colour = self
+            .style
+            .peak
+            .unwrap_or(visuals.widgets.inactive.fg_stroke.color);
+        let peak_y = rect.top() + rect.height() * self.normalise(peak);
+        let peak_y = peak_y.clamp(rect.top() + 1.0, rect.bottom() - 1.0);
+        let peak_rect =
+            Rect::from_center_size(pos2(rect.center().x, peak_y), vec2(rect.width(), 2.0));
+        ui.painter().rect_filled(peak_rect, 0.0, peak_colour);
+    }
+}
+
+impl Widget for GainReductionMeter<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let label_width = 2.0 * self.text_size;
+        let gap = 0.25 * self.text_size;
+        let height = self.height.unwrap_or_else(|| ui.spacing().slider_width);
+        let size = vec2(label_width + gap + self.meter_width, height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let peak = self.next_peak(ui, &response);
+        if ui.is_rect_visible(rect) {
+            let (labels, meter) = rect.split_left_right_at_x(rect.left() + label_width);
+            let meter = meter.with_min_x(meter.left() + gap);
+            self.labels_ui(ui, labels);
+            self.meter_ui(ui, meter, peak);
+        }
+        response
+    }
+}
@@ -0,0 +1,140 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use egui::{Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget, epaint, vec2};
+
+use crate::FaderStyle;
+
+/// Blocks larger than this are decimated so the trace stays cheap to paint.
+const GONIOMETER_MAX_POINTS: usize = 2048;
+
+/// A goniometer showing the stereo image of a block of samples as a Lissajous trace.
+///
+/// Mono signals draw a vertical line, wide signals spread sideways and out of phase signals
+/// lie along the horizontal. Samples are `[left, right]` pairs from `-1.0` to `1.0`.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let my_block: Vec<[f32; 2]> = (0..256).map(|i| [(i as f32 * 0.1).sin(), (i as f32 * 0.13).sin()]).collect();
+/// ui.add(egui_fader::Goniometer::new(&my_block));
+/// # });
+/// ```
+pub struct Goniometer<'a> {
+    samples: &'a [[f32; 2]],
+    size: Option<f32>,
+    gain: f32,
+    style: FaderStyle,
+}
+
+impl<'a> Goniometer<'a> {
+    /// Creates a goniometer for a block of `[left, right]` samples.
+    pub fn new(samples: &'a [[f32; 2]]) -> Self {
+        Self {
+            samples,
+            size: None,
+            gain: 1.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the width and height of the goniometer in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Scale the samples before drawing, e.g. to make quiet material visible. Default: `1.0`.
+    #[inline]
+    pub fn gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Set the colours of the goniometer. The `channel` colour is the background, `signal` the
+    /// trace and `label_text` the axes.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn goniometer_ui(&self, ui: &Ui, rect: Rect) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let background = self.style.channel.unwrap_or(visuals.extreme_bg_color);
+        ui.painter().rect_filled(rect, corner, background);
+
+        // Left and right axes on the diagonals, mid vertical and side horizontal.
+        let axis_colour = self
+            .style
+            .label_text
+            .unwrap_or(visuals.weak_text_color())
+            .gamma_multiply(0.5);
+        let axis = Stroke::new(1.0, axis_colour);
+        let centre = rect.center();
+        let radius = 0.5 * rect.width();
+        ui.painter()
+            .line_segment([rect.left_top(), rect.right_bottom()], axis);
+        ui.painter()
+            .line_segment([rect.right_top(), rect.left_bottom()], axis);
+        ui.painter().vline(centre.x, rect.y_range(), axis);
+        ui.painter().hline(rect.x_range(), centre.y, axis);
+
+        let step = self.samples.len().div_ceil(GONIOMETER_MAX_POINTS).max(1);
+        let points: Vec<Pos2> = self
+            .samples
+            .iter()
+            .step_by(step)
+            .map(|&[left, right]| {
+                let offset = goniometer_offset(self.gain * left, self.gain * right);
+                centre + radius * offset.clamp(Vec2::splat(-1.0), Vec2::splat(1.0))
+            })
+            .collect();
+        if points.len() < 2 {
+            return;
+        }
+        let trace = self
+            .style
+            .signal
+            .unwrap_or(visuals.widgets.active.fg_stroke.color)
+            .gamma_multiply(0.7);
+        ui.painter()
+            .with_clip_rect(rect)
+            .add(epaint::PathShape::line(points, Stroke::new(1.0, trace)));
+    }
+}
+
+impl Widget for Goniometer<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self.size.unwrap_or_else(|| ui.spacing().slider_width);
+        let (rect, response) = ui.allocate_exact_size(vec2(size, size), Sense::hover());
+        if ui.is_rect_visible(rect) {
+            self.goniometer_ui(ui, rect);
+        }
+        response
+    }
+}
+
+/// Offset of a sample from the centre, with mid pointing up and side to the right.
+fn goniometer_offset(left: f32, right: f32) -> Vec2 {
+    let mid = (left + right) * FRAC_1_SQRT_2;
+    let side = (right - left) * FRAC_1_SQRT_2;
+    vec2(side, -mid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mono_is_vertical_and_out_of_phase_is_horizontal() {
+        let mono = goniometer_offset(0.5, 0.5);
+        assert!(mono.x.abs() < 1e-6 && mono.y < 0.0);
+        let out_of_phase = goniometer_offset(-0.5, 0.5);
+        assert!(out_of_phase.y.abs() < 1e-6 && out_of_phase.x > 0.0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{Fader, INFINITY};
+
+/// A VCA (or DCA) group: one master level offsets the levels of its member faders.
+///
+/// The master is a fader of its own, at 0 dB the members are unchanged. Members show their own
+/// level with their handle and the effective level with a ghost handle, see
+/// [`Fader::vca_offset`]. Use [`FaderGroup::effective_level`] for the gain actually applied.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut drums = egui_fader::FaderGroup::default();
+/// # let mut members: [f32; 3] = [-6.0, -10.0, 0.0];
+/// ui.horizontal(|ui| {
+///     ui.add(egui_fader::Fader::mono(&mut drums.level, -100.0));
+///     for level in &mut members {
+///         ui.add(drums.member(egui_fader::Fader::mono(level, -20.0)));
+///     }
+/// });
+/// let kick_gain = drums.effective_level(members[0]);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaderGroup {
+    /// The master level in dB, added to the level of every member.
+    pub level: f32,
+}
+
+impl FaderGroup {
+    /// Creates a group with the master at `level`.
+    pub fn new(level: f32) -> Self {
+        Self { level }
+    }
+
+    /// The level of a member after the master is applied.
+    pub fn effective_level(&self, member_level: f32) -> f32 {
+        offset_level(member_level, self.level)
+    }
+
+    /// Show the master's offset on a member fader.
+    pub fn member<'a>(&self, fader: Fader<'a>) -> Fader<'a> {
+        fader.vca_offset(self.level)
+    }
+}
+
+/// Offset a level in dB, silence stays silent and a silent master silences everything.
+pub(crate) fn offset_level(level: f32, offset: f32) -> f32 {
+    if level == -INFINITY || offset == -INFINITY {
+        -INFINITY
+    } else {
+        level + offset
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn master_offsets_members() {
+        let group = FaderGroup::new(-6.0);
+        assert_eq!(group.effective_level(-4.0), -10.0);
+        assert_eq!(group.effective_level(-INFINITY), -INFINITY);
+        let silent = FaderGroup::new(-INFINITY);
+        assert_eq!(silent.effective_level(0.0), -INFINITY);
+    }
+}
@@ -0,0 +1,502 @@
+use std::borrow::Cow;
+use std::f32::consts::{FRAC_PI_4, PI};
+
+use egui::emath::OrderedFloat;
+use egui::{
+    Align2, FontId, Modifiers, Pos2, Response, Sense, Stroke, Ui, Vec2, Widget, emath, epaint,
+    lerp, pos2, vec2,
+};
+
+use crate::{
+    DEFAULT_INCREMENTS, FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, GetSetValue,
+    any_modifier_held, breakpoint, get, level_readout, normalised_with_breakpoints, set,
+    value_with_breakpoints,
+};
+
+/// Angle of the bottom of the knob's travel, clockwise from the right in screen space.
+const KNOB_START_ANGLE: f32 = 3.0 * FRAC_PI_4;
+/// The knob turns through 270°.
+const KNOB_SWEEP: f32 = 1.5 * PI;
+
+/// A rotary control with the same increments, taper and drag behaviour as a [`crate::Fader`].
+///
+/// Drag up or down to turn the knob, hold a fine drag modifier for more precision and double
+/// click to return to the neutral level.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_send: f32 = -10.0;
+/// ui.add(egui_fader::Knob::new(&mut my_send).signal(-20.0));
+/// # });
+/// ```
+pub struct Knob<'a> {
+    get_set_value: GetSetValue<'a>,
+    integral: bool,
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    neg_infinity_at_bottom: bool,
+    neutral_level: f32,
+    signal: Option<f32>,
+    diameter: Option<f32>,
+    drag_distance: f32,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    show_value: bool,
+    infinity_text: String,
+    decimals: usize,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> Knob<'a> {
+    /// Creates a knob controlling `level`.
+    pub fn new<Num: emath::Numeric>(level: &'a mut Num) -> Self {
+        let get_set_value = move |v: Option<f64>| {
+            if let Some(v) = v {
+                *level = Num::from_f64(v);
+            }
+            level.to_f64()
+        };
+        Self {
+            get_set_value: Box::new(get_set_value),
+            integral: Num::INTEGRAL,
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            neg_infinity_at_bottom: true,
+            neutral_level: 0.0,
+            signal: None,
+            diameter: None,
+            drag_distance: 200.0,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            show_value: true,
+            infinity_text: "-∞".to_owned(),
+            decimals: 1,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments that make up the knob's range, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        let increments = increments.into();
+        debug_assert!(
+            increments.is_sorted_by(|a, b| OrderedFloat(*a) < OrderedFloat(*b)),
+            "Increments must be unique and in ascending order."
+        );
+        self.increments = increments;
+        self
+    }
+
+    /// Set a relative weight for each segment between increments, see
+    /// [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        let segment_weights = segment_weights.into();
+        debug_assert!(
+            segment_weights.iter().all(|weight| *weight > 0.0),
+            "Segment weights must be positive."
+        );
+        self.segment_weights = Some(segment_weights);
+        self
+    }
+
+    /// Set whether the bottom of the knob sets the level to `NEG_INFINITY`.
+    /// Default: `true`.
+    #[inline]
+    pub fn neg_infinity_at_bottom(mut self, neg_infinity_at_bottom: bool) -> Self {
+        self.neg_infinity_at_bottom = neg_infinity_at_bottom;
+        self
+    }
+
+    /// Set the level the knob returns to when double clicked.
+    #[inline]
+    pub fn neutral_level(mut self, neutral_level: f32) -> Self {
+        self.neutral_level = neutral_level;
+        self
+    }
+
+    /// Show a signal as an arc inside the knob.
+    #[inline]
+    pub fn signal(mut self, signal: f32) -> Self {
+        self.signal = Some(signal);
+        self
+    }
+
+    /// Set the diameter of the knob in points.
+    /// The default is twice `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Set how far the pointer must be dragged to turn the knob through its whole range.
+    /// Default: `200.0` points.
+    #[inline]
+    pub fn drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Show the level underneath the knob. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Set the text shown for a `NEG_INFINITY` level, see [`crate::Fader::infinity_text`].
+    #[inline]
+    pub fn infinity_text(mut self, infinity_text: impl ToString) -> Self {
+        self.infinity_text = infinity_text.to_string();
+        self
+    }
+
+    /// Set the number of decimals shown in the level underneath the knob. Default: `1`.
+    #[inline]
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the knob. The `rail` is the track, `rail_fill` the level arc,
+    /// `handle_fill` the cap and `signal` the signal arc.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn weights(&self) -> Option<&[f32]> {
+        let weights = self.segment_weights.as_deref();
+        debug_assert!(
+            weights.is_none_or(|weights| weights.len() + 1 == self.increments.len()),
+            "There must be one segment weight per pair of increments."
+        );
+        weights
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        self.normalise_level(value as f64) as f32
+    }
+
+    /// Like [`Self::normalise`] in `f64`, the same as [`crate::Fader`] maps its level.
+    fn normalise_level(&self, level: f64) -> f64 {
+        let weights = self.weights();
+        normalised_with_breakpoints(level, &self.increments, |index| {
+            breakpoint(index, &self.increments, weights)
+        })
+    }
+
+    /// The level at a `normalised` position, the inverse of [`Self::normalise_level`].
+    fn denormalise_level(&self, normalised: f64) -> f64 {
+        let weights = self.weights();
+        let level = value_with_breakpoints(normalised, &self.increments, |index| {
+            breakpoint(index, &self.increments, weights)
+        });
+        if level == f64::NEG_INFINITY && !self.neg_infinity_at_bottom {
+            self.increments[0] as f64
+        } else {
+            level
+        }
+    }
+
+    fn get_level(&mut self) -> f64 {
+        get(&mut self.get_set_value)
+    }
+
+    fn set_level(&mut self, level: f64) {
+        let level = if self.integral {
+            level.max(self.increments[0] as f64).round()
+        } else {
+            level
+        };
+        set(&mut self.get_set_value, level)
+    }
+
+    fn interaction(&mut self, ui: &Ui, response: &Response) {
+        if response.double_clicked() {
+            let min = self.increments[0];
+            let max = self.increments[self.increments.len() - 1];
+            self.set_level(self.neutral_level.clamp(min, max) as f64);
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let speed = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        } / self.drag_distance;
+        let level = self.get_level();
+        let normalised = self.normalise_level(level);
+        let delta = (-response.drag_delta().y * speed) as f64;
+        if let Some(normalised) = drag_normalised_f64(ui, response, normalised, delta) {
+            self.set_level(self.denormalise_level(normalised));
+        }
+    }
+
+    fn knob_ui(&mut self, ui: &Ui, response: &Response, center: Pos2, radius: f32) {
+        let level = self.get_level();
+        let normalised = self.normalise_level(level) as f32;
+        let track_width = knob_body_ui(ui, response, &self.style, center, radius, 0.0, normalised);
+        if let Some(signal) = self.signal {
+            let signal_colour = self
+                .style
+                .signal
+                .unwrap_or(ui.visuals().widgets.active.fg_stroke.color);
+            let signal_radius = radius - 2.0 * track_width;
+            let signal = self.normalise(signal);
+            let stroke = Stroke::new(0.5 * track_width, signal_colour);
+            arc_ui(ui, center, signal_radius, 0.0, signal, stroke);
+        }
+    }
+
+    fn level_text(&self, level: f64) -> String {
+        level_readout(level, &self.infinity_text, self.decimals)
+    }
+}
+
+impl Widget for Knob<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let text_height = if self.show_value {
+            self.text_size * 1.25
+        } else {
+            0.0
+        };
+        let size = vec2(diameter, diameter + text_height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        let old_level = self.get_level();
+        self.interaction(ui, &response);
+        if ui.is_rect_visible(rect) {
+            let radius = 0.5 * diameter;
+            let center = pos2(rect.center().x, rect.top() + radius);
+            self.knob_ui(ui, &response, center, radius);
+            if self.show_value {
+                let level = self.get_level();
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    rect.center_bottom(),
+                    Align2::CENTER_BOTTOM,
+                    self.level_text(level),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        if self.get_level() != old_level {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Move a normalised position by `delta` while dragging.
+/// The unrounded position is kept in memory during the drag so small movements aren't lost to
+/// rounding or snapping. Returns the new position when it changed.
+pub(crate) fn drag_normalised(
+    ui: &Ui,
+    response: &Response,
+    current: f32,
+    delta: f32,
+) -> Option<f32> {
+    drag_normalised_f64(ui, response, current as f64, delta as f64).map(|position| position as f32)
+}
+
+/// Like [`drag_normalised`] in `f64`, for levels that keep their precision like a fader's.
+pub(crate) fn drag_normalised_f64(
+    ui: &Ui,
+    response: &Response,
+    current: f64,
+    delta: f64,
+) -> Option<f64> {
+    let id = response.id.with("knob");
+    if response.drag_started() {
+        ui.data_mut(|data| data.insert_temp(id, current));
+    }
+    let mut new_position = None;
+    if response.dragged() && delta != 0.0 {
+        let start = ui.data(|data| data.get_temp::<f64>(id)).unwrap_or(current);
+        let normalised = (start + delta).clamp(0.0, 1.0);
+        ui.data_mut(|data| data.insert_temp(id, normalised));
+        new_position = Some(normalised);
+    }
+    if response.drag_stopped() {
+        ui.data_mut(|data| data.remove::<f64>(id));
+    }
+    new_position
+}
+
+/// Paint the track, the arc filled from `fill_from` to the `normalised` position and the cap
+/// with a pointer. Returns the width of the track.
+pub(crate) fn knob_body_ui(
+    ui: &Ui,
+    response: &Response,
+    style: &FaderStyle,
+    center: Pos2,
+    radius: f32,
+    fill_from: f32,
+    normalised: f32,
+) -> f32 {
+    let visuals = ui.style().interact(response);
+    let track_width = (radius * 0.15).max(2.0);
+    let arc_radius = radius - 0.5 * track_width;
+    let track_colour = style.rail.unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+    let fill_colour = style.rail_fill.unwrap_or(ui.visuals().selection.bg_fill);
+    let track_stroke = Stroke::new(track_width, track_colour);
+    arc_ui(ui, center, arc_radius, 0.0, 1.0, track_stroke);
+    let fill_stroke = Stroke::new(track_width, fill_colour);
+    arc_ui(ui, center, arc_radius, fill_from, normalised, fill_stroke);
+
+    // Cap with a pointer line.
+    let cap_radius = arc_radius - 2.5 * track_width;
+    let cap_fill = style.handle_fill.unwrap_or(visuals.bg_fill);
+    let cap_stroke = style.handle_stroke.unwrap_or(visuals.fg_stroke);
+    ui.painter().add(epaint::CircleShape {
+        center,
+        radius: cap_radius + visuals.expansion,
+        fill: cap_fill,
+        stroke: cap_stroke,
+    });
+    let direction = Vec2::angled(angle_from_normalised(normalised));
+    ui.painter().line_segment(
+        [
+            center + direction * 0.3 * cap_radius,
+            center + direction * cap_radius,
+        ],
+        cap_stroke,
+    );
+    track_width
+}
+
+/// Angle of a normalised position on a knob, clockwise from the right in screen space.
+pub(crate) fn angle_from_normalised(normalised: f32) -> f32 {
+    KNOB_START_ANGLE + KNOB_SWEEP * normalised.clamp(0.0, 1.0)
+}
+
+/// Paint an arc of a knob's travel between two normalised positions.
+pub(crate) fn arc_ui(ui: &Ui, center: Pos2, radius: f32, from: f32, to: f32, stroke: Stroke) {
+    let (from, to) = if from <= to { (from, to) } else { (to, from) };
+    if to - from <= 0.0 {
+        return;
+    }
+    let segments = ((to - from) * 48.0).ceil().max(1.0) as usize;
+    let points = (0..=segments)
+        .map(|i| {
+            let t = lerp(from..=to, i as f32 / segments as f32);
+            center + radius * Vec2::angled(angle_from_normalised(t))
+        })
+        .collect();
+    ui.painter().add(epaint::PathShape::line(points, stroke));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn knob_turns_clockwise_through_the_top() {
+        let top = Vec2::angled(angle_from_normalised(0.5));
+        assert!(top.x.abs() < 1e-6 && top.y < 0.0);
+        let bottom = Vec2::angled(angle_from_normalised(0.0));
+        let end = Vec2::angled(angle_from_normalised(1.0));
+        assert!(bottom.x < 0.0 && bottom.y > 0.0);
+        assert!(end.x > 0.0 && end.y > 0.0);
+    }
+
+    #[test]
+    fn drag_position_maps_back_to_level() {
+        let mut level: f32 = 0.0;
+        let knob = Knob::new(&mut level);
+        assert_eq!(knob.denormalise_level(knob.normalise_level(-10.0)), -10.0);
+        assert_eq!(knob.denormalise_level(0.0), f64::NEG_INFINITY);
+        let knob = knob.neg_infinity_at_bottom(false);
+        assert_eq!(knob.denormalise_level(0.0), -100.0);
+    }
+
+    #[test]
+    fn f64_levels_keep_their_precision() {
+        let start = -6.000_000_123_f64;
+        let mut level = start;
+        let mut highest = start;
+        let ctx = egui::Context::default();
+        let mut rect = egui::Rect::NOTHING;
+        // Drag up and back down again in one gesture.
+        for (frame, offset) in [0.0, 0.0, -10.0, -20.0, -10.0, 0.0].into_iter().enumerate() {
+            let mut input = egui::RawInput::default();
+            if frame > 0 {
+                let pos = rect.center() + vec2(0.0, offset);
+                input.events.push(egui::Event::PointerMoved(pos));
+                if frame == 1 {
+                    input.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed: true,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+            }
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect = ui.add(Knob::new(&mut level)).rect;
+                });
+            });
+            highest = highest.max(level);
+        }
+        assert!(highest > start + 1.0);
+        assert!((level - start).abs() < 1e-9, "{level}");
+    }
+
+    #[test]
+    fn level_text_follows_the_options() {
+        let mut level: f32 = 0.0;
+        let knob = Knob::new(&mut level);
+        assert_eq!(knob.level_text(f64::NEG_INFINITY), "-∞");
+        assert_eq!(knob.level_text(-6.25), "-6.2");
+        let knob = knob.infinity_text("Off").decimals(2);
+        assert_eq!(knob.level_text(f64::NEG_INFINITY), "Off");
+        assert_eq!(knob.level_text(-6.25), "-6.25");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "Increments must be unique and in ascending order."]
+    fn unsorted_increments_panic() {
+        let mut level: f32 = 0.0;
+        let _ = Knob::new(&mut level).increments(vec![0.0, -10.0]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "There must be one segment weight per pair of increments."]
+    fn segment_weight_count_must_match() {
+        let mut level: f32 = 0.0;
+        let knob = Knob::new(&mut level).segment_weights(vec![1.0, 2.0]);
+        knob.normalise(-10.0);
+    }
+}
@@ -1,17 +1,128 @@
-use egui::{Align2, Color32, CornerRadius, FontId, NumExt, TextStyle, epaint};
+use std::borrow::Cow;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::rc::Rc;
+use std::sync::{Arc, Weak};
+
+use egui::{
+    Align, Align2, Color32, Context, CornerRadius, EventFilter, FontId, Frame, Galley, Id, Key,
+    Margin, Mesh, Modifiers, NumExt, Painter, Stroke, TextEdit, TextStyle, TextureId, WidgetInfo,
+    emath, epaint, mutex::Mutex,
+};
 
 use egui::emath::OrderedFloat;
 use egui::style::HandleShape;
+use egui::{Pos2, Rect, Response, Sense, Ui, Vec2, Widget, pos2, vec2};
 use egui::{Rangef, lerp, remap, remap_clamp};
-use egui::{Rect, Response, Sense, Ui, Vec2, Widget, pos2, vec2};
 
+mod atomic;
+mod balance_meter;
+mod buttons;
+mod channel_strip;
+mod config;
+mod crossfader;
+mod db;
+mod db_drag_value;
+mod eq_band_array;
+mod fader_bank;
+mod gain_reduction;
+#[cfg(feature = "goniometer")]
+mod goniometer;
+mod group;
+mod knob;
+#[cfg(feature = "ebur128")]
+mod loudness;
+#[cfg(feature = "ebur128")]
+mod loudness_analyser;
+mod master_fader;
+mod meter_bridge;
+mod meter_state;
+pub mod midi;
+mod mixer_matrix;
+mod output;
+mod overlay;
+mod pan;
 mod peak;
+mod selection;
+mod slim_meter;
+mod style;
+mod trim;
+mod xy_pad;
 
+pub use atomic::AtomicLevel;
+pub use balance_meter::BalanceMeter;
+pub use buttons::{MuteButton, SoloButton};
+pub use channel_strip::{ChannelStrip, ChannelStripState};
+pub use config::{ConfigError, FaderConfig};
+pub use crossfader::{CrossfadeCurve, Crossfader};
+pub use db::{
+    amplitude_to_db, amplitude_to_db_fast, block_peak_db, db_to_amplitude, db_to_amplitude_fast,
+};
+pub use db_drag_value::DbDragValue;
+pub use eq_band_array::EqBandArray;
+pub use fader_bank::FaderBank;
+pub use gain_reduction::GainReductionMeter;
+#[cfg(feature = "goniometer")]
+pub use goniometer::Goniometer;
+pub use group::FaderGroup;
+pub use knob::Knob;
+#[cfg(feature = "ebur128")]
+pub use loudness::{Loudness, LoudnessMeter};
+#[cfg(feature = "ebur128")]
+pub use loudness_analyser::LoudnessAnalyser;
+pub use master_fader::MasterFader;
+pub use meter_bridge::MeterBridge;
+pub use meter_state::MeterState;
+pub use mixer_matrix::{MatrixSend, MixerMatrix};
+pub use output::FaderOutput;
+pub use overlay::{FaderRects, FaderState};
+pub use pan::Pan;
 use peak::*;
+pub use selection::FaderSelection;
+pub use slim_meter::SlimMeter;
+pub use style::{FaderStyle, MeterGradient};
+pub use trim::Trim;
+pub use xy_pad::XyPad;
 
 const FADER_FINE_DRAG_RATIO: f32 = 0.2;
+const FADER_COARSE_DRAG_RATIO: f32 = 5.0;
+const FADER_FINE_DRAG_MODIFIERS: Modifiers = Modifiers {
+    alt: true,
+    ctrl: true,
+    shift: true,
+    mac_cmd: false,
+    command: false,
+};
+const FLING_FRICTION: f32 = 6.0;
+const FLING_MIN_VELOCITY: f32 = 20.0;
+const DEFAULT_INCREMENTS: [f32; 5] = [-100.0, -30.0, -10.0, 0.0, 10.0];
+const DMX_INCREMENTS: [f32; 5] = [0.0, 63.75, 127.5, 191.25, 255.0];
 const INFINITY: f32 = f32::INFINITY;
 
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
+    (get_set_value)(None)
+}
+
+fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
+    (get_set_value)(Some(value));
+}
+
+type NumFormatter<'a> = Box<dyn 'a + Fn(f64) -> String>;
+type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
+type LabelFormatter<'a> = Box<dyn 'a + Fn(f32, usize) -> String>;
+
+type OverlayPainter<'a> = Box<dyn 'a + Fn(&Painter, &FaderRects, &FaderState)>;
+
+/// Called with the level when a gesture starts or ends.
+type GestureCallback<'a> = Box<dyn 'a + FnMut(f64)>;
+
+/// Extra contents added to a [`Fader`] ui, e.g. context menu entries.
+type AddContents<'a> = Box<dyn 'a + FnOnce(&mut Ui)>;
+
 /// Specifies the signal kind the [`Fader`] will display.
 #[derive(Copy, Clone, PartialEq)]
 enum SignalKind {
@@ -19,6 +130,186 @@ enum SignalKind {
     Stereo([f32; 2]),
 }
 
+impl SignalKind {
+    /// The value of each channel, left first.
+    fn channels(&self) -> &[f32] {
+        match self {
+            Self::Mono(value) => std::slice::from_ref(value),
+            Self::Stereo(values) => values,
+        }
+    }
+}
+
+/// How the level readout and the increment labels are displayed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DisplayMode {
+    /// Show values in the units of the increments (e.g. dB).
+    #[default]
+    Level,
+
+    /// Show the fader position as a percentage (0–100 %) of its travel.
+    Percent,
+}
+
+/// How dragging moves the [`Fader`] handle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DragMode {
+    /// Move the level by the distance dragged, so the handle doesn't jump to the pointer.
+    /// Fine and coarse adjustment only apply in this mode.
+    #[default]
+    Relative,
+
+    /// Set the level from the pointer position, like [`egui::Slider`].
+    Absolute,
+}
+
+/// What a [`Fader`] does when the bound level is changed from outside, e.g. by automation,
+/// in the middle of a drag.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DragConflict {
+    /// Keep dragging from the new level, as if the handle had been moved there.
+    #[default]
+    Relative,
+
+    /// Put back the level the drag left, holding it until the drag ends.
+    DragWins,
+
+    /// Leave the new level alone and ignore the rest of the drag.
+    ExternalWins,
+}
+
+/// What double clicking a [`Fader`] does.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DoubleClickAction {
+    /// Return the level to the neutral level.
+    #[default]
+    Neutral,
+
+    /// Set the level to the bottom of the fader, `NEG_INFINITY` unless disabled.
+    Bottom,
+
+    /// Open the text edit to type an exact level.
+    EnterValue,
+
+    /// Do nothing.
+    None,
+}
+
+/// What a [`Fader`] does with a bound level outside its increments, e.g. `+12` set by automation
+/// on a fader whose top increment is `+10`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum OutOfRange {
+    /// Pin the handle to the end of the scale but leave the level alone until the fader is used.
+    /// A drag then starts from the end of the scale, so the level jumps back into range.
+    #[default]
+    ClampDisplay,
+
+    /// Clamp the bound level into range as soon as the fader is shown, marking the response
+    /// as changed.
+    ClampStored,
+
+    /// Continue the top segment past the top increment at the same rate. The handle is still
+    /// pinned to the top, but drags and key presses move from the actual level, so dragging down
+    /// from `+12` passes smoothly through `+10`. Levels below the range are pinned as with
+    /// [`Self::ClampDisplay`].
+    Extend,
+}
+
+/// How the peak level is marked on the meter of a [`Fader`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PeakMarker {
+    /// A square the width of the meter channel.
+    #[default]
+    Square,
+
+    /// A thin horizontal line across the meter channel.
+    Line,
+
+    /// A triangle beside the meter channel pointing at the peak.
+    Triangle,
+}
+
+/// What a meter shows for a signal that is NaN or `+∞`, e.g. from a misbehaving DSP chain.
+/// The peak hold sees the value shown, so it holds the top of the scale with [`Self::Clamp`]
+/// and ignores the invalid value otherwise.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InvalidSignal {
+    /// Show silence, as if the signal were `NEG_INFINITY`.
+    #[default]
+    Silence,
+
+    /// Show the top of the scale, so the fault looks like a clipped signal.
+    Clamp,
+
+    /// Show silence with a cross over the meter so the fault stands out.
+    Error,
+}
+
+impl InvalidSignal {
+    /// The signal to show in place of `signal`, and whether it was invalid.
+    pub(crate) fn resolve(self, signal: f32, top: f32) -> (f32, bool) {
+        if !signal.is_nan() && signal != f32::INFINITY {
+            (signal, false)
+        } else if self == Self::Clamp {
+            (top, true)
+        } else {
+            (-INFINITY, true)
+        }
+    }
+}
+
+/// Who has the level during a drag, stored in memory between frames.
+#[derive(Copy, Clone, Debug)]
+enum DragOwner {
+    /// The drag, which last left the level here.
+    Drag(f64),
+    /// The level was changed from outside and the drag gave way, see [`DragConflict`].
+    External,
+}
+
+/// Laid out text of a fader, stored in memory between frames so it is only shaped again when
+/// it changes.
+#[derive(Clone, Default)]
+struct CachedGalleys {
+    /// The galleys point into this font atlas, so are laid out again when egui replaces it.
+    atlas: Weak<Mutex<epaint::TextureAtlas>>,
+    /// A hash of the text, fonts and colour.
+    key: u64,
+    /// Each galley with the index of the label it shows.
+    galleys: Arc<[(usize, Arc<Galley>)]>,
+}
+
+/// Inertia of a touch drag, stored in memory between frames.
+#[derive(Copy, Clone, Debug, Default)]
+struct Fling {
+    touch: bool,
+    /// Points per second.
+    velocity: f32,
+}
+
+/// Settings shared by every fader in the app, kept together so a fader reads them at once.
+#[derive(Copy, Clone, Debug, Default)]
+struct AppSettings {
+    high_contrast: bool,
+    reduced_motion: bool,
+}
+
+/// What the meter shows on one frame, see [`Fader::update_meter`].
+struct MeterFrame {
+    peak: SignalKind,
+    clip_lit: bool,
+    repaint: bool,
+    #[cfg(feature = "accesskit")]
+    announcement: Option<String>,
+}
+
 /// Wrapper of [`PeakDetector`] to pass any variant of [`SignalKind`].
 #[derive(Clone, Debug)]
 enum FaderPeak {
@@ -43,6 +334,29 @@ impl FaderPeak {
             }
         }
     }
+
+    /// Whether the peak has the same number of channels as `signal`.
+    pub fn fits(&self, signal: SignalKind) -> bool {
+        matches!(
+            (self, signal),
+            (Self::Mono(..), SignalKind::Mono(..)) | (Self::Stereo(..), SignalKind::Stereo(..))
+        )
+    }
+
+    pub fn buffer_size(&self) -> usize {
+        match self {
+            Self::Mono(detector) | Self::Stereo([detector, _]) => detector.buffer_size(),
+        }
+    }
+
+    pub fn current(&self) -> SignalKind {
+        match self {
+            Self::Mono(detector) => SignalKind::Mono(detector.current()),
+            Self::Stereo([left_detector, right_detector]) => {
+                SignalKind::Stereo([left_detector.current(), right_detector.current()])
+            }
+        }
+    }
 }
 
 /// See the signal and control the level of some input.
@@ -58,7 +372,9 @@ impl FaderPeak {
 /// E.g. The interval [-100, -30, -10, 0, 10] gives the first 25% of the interval to [-100, -30], the next 25% to [-30, -10] etc.
 ///
 /// New Fader instances are created with `Fader::mono()` or `Fader::stereo()` depending on the signal
-/// type.
+/// type. The level can be any numeric type (`f32`, `f64`, integers etc.), see [`emath::Numeric`].
+/// Integer levels are rounded and cannot be set to `NEG_INFINITY`, the bottom of the fader instead
+/// sets them to the first increment.
 ///
 /// The default (and currently only) behaviour sets the level to `NEG_INFINITY` when the
 /// fader handle is at the bottom of the fader.
@@ -79,37 +395,348 @@ impl FaderPeak {
 ///  Code has been adapted from [`egui::Slider`]
 ///  https://docs.rs/egui/latest/egui/widgets/struct.Slider.html
 pub struct Fader<'a> {
-    level: &'a mut f32,
+    get_set_value: GetSetValue<'a>,
+    integral: bool,
+    normalized: bool,
+    external_normalised: Option<f32>,
     signal: SignalKind,
-    increments: Vec<f32>,
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    /// Normalised positions of the increments, worked out the first time weighted segments are
+    /// normalised.
+    weighted_breakpoints: OnceCell<Vec<f32>>,
+    neg_infinity_at_bottom: bool,
+    infinity_text: String,
+    infinity_label: bool,
+    bipolar: bool,
     handle_shape: Option<HandleShape>,
+    handle_image: Option<TextureId>,
+    skeuomorphic_handle: bool,
+    handle_size: Option<f32>,
+    rail_width: Option<f32>,
+    meter_width: Option<f32>,
     neutral_level: f32,
+    neutral_line: bool,
+    show_labels: bool,
+    show_text: bool,
+    labels_only: bool,
+    ticks: bool,
+    minor_ticks: usize,
+    tick_lengths: [f32; 2],
+    reference_lines: Cow<'a, [f32]>,
+    scroll_step: f32,
+    key_step: f32,
+    page_step: f32,
+    jump_on_click: bool,
+    interactive: bool,
+    sense: Sense,
+    id_salt: Option<Id>,
+    drag_anywhere: bool,
+    drag_mode: DragMode,
+    drag_conflict: DragConflict,
+    drag_speed: f32,
+    fling: bool,
+    commit_on_release: bool,
+    on_gesture_start: Option<GestureCallback<'a>>,
+    on_gesture_end: Option<GestureCallback<'a>>,
+    selection: Option<(&'a mut FaderSelection, usize)>,
+    group_drag_modifiers: Modifiers,
+    detent_modifiers: Modifiers,
+    quick_dip_modifiers: Modifiers,
+    quick_dip_momentary: bool,
+    double_click_action: DoubleClickAction,
+    out_of_range: OutOfRange,
+    hit_margin: f32,
+    min_touch_target: f32,
+    context_menu: bool,
+    context_menu_items: Option<AddContents<'a>>,
+    tooltip: bool,
+    tooltip_signal: bool,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    coarse_drag_modifiers: Modifiers,
+    coarse_drag_ratio: f32,
     text_size: f32,
+    readout_font: Option<FontId>,
+    label_font: Option<FontId>,
+    channel_label_font: Option<FontId>,
+    channel_labels: [Cow<'a, str>; 2],
+    style: FaderStyle,
+    high_contrast: Option<bool>,
+    reduced_motion: Option<bool>,
+    accent_color: Option<Color32>,
+    frame: Option<Frame>,
+    overlay: Option<OverlayPainter<'a>>,
+    muted: bool,
+    invalid_signal: InvalidSignal,
+    invalid_channels: [bool; 2],
+    vca_offset: Option<f32>,
+    overload_shading: bool,
+    overload_level: f32,
+    display_mode: DisplayMode,
+    custom_formatter: Option<NumFormatter<'a>>,
+    accessibility_text: Option<NumFormatter<'a>>,
+    custom_parser: Option<NumParser<'a>>,
+    label_formatter: Option<LabelFormatter<'a>>,
+    decimals: Option<usize>,
+    suffix: String,
+    suffix_on_labels: bool,
     height: Option<f32>,
+    width: Option<f32>,
+    smoothing: Option<f32>,
     peak_buffer_size: usize,
+    meter_repaint: Option<f32>,
+    max_meter_refresh: Option<f32>,
+    peak_marker: PeakMarker,
+    peak_thickness: f32,
+    clip_indicator: bool,
+    meter_state: Option<&'a mut MeterState>,
+    batch: Option<RefCell<&'a mut Mesh>>,
+    peak: Option<SignalKind>,
+    clip_lit: bool,
+    #[cfg(feature = "accesskit")]
+    announcement: Option<String>,
+}
+
+impl Fader<'_> {
+    /// The level when the most recent drag gesture of the fader with this `id` started.
+    /// This is valid while dragging and on the frame the drag stops, i.e. when
+    /// [`Response::drag_stopped`] is true, making it suitable for begin/end automation events.
+    pub fn drag_start_level(ctx: &Context, id: Id) -> Option<f64> {
+        ctx.data(|data| data.get_temp(drag_start_id(id)))
+    }
+
+    /// The held peak of each channel of the fader with this `id`, as shown on its meter.
+    /// One value for a mono fader, left and right for a stereo fader. `None` until the fader has
+    /// been shown.
+    pub fn peak_in_memory(ctx: &Context, id: Id) -> Option<Vec<f32>> {
+        ctx.data(|data| data.get_temp::<MeterState>(peak_id(id)))
+            .and_then(|state| state.peaks())
+    }
+
+    /// Turn high contrast on or off for every fader that doesn't set
+    /// [`Fader::high_contrast`] itself.
+    pub fn set_high_contrast(ctx: &Context, high_contrast: bool) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<AppSettings>(app_settings_id())
+                .high_contrast = high_contrast;
+        });
+    }
+
+    /// Turn reduced motion on or off for every fader that doesn't set
+    /// [`Fader::reduced_motion`] itself. The held peaks of a [`MeterBridge`] and a
+    /// [`GainReductionMeter`] follow it too.
+    pub fn set_reduced_motion(ctx: &Context, reduced_motion: bool) {
+        ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<AppSettings>(app_settings_id())
+                .reduced_motion = reduced_motion;
+        });
+    }
+
+    /// Clear the held peaks and the clip light of the fader with this `id`.
+    pub fn reset_peaks(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| {
+            data.remove::<MeterState>(peak_id(id));
+        });
+    }
+
+    /// Clear the held peaks and clip lights of every fader, e.g. from a "clear clips" button.
+    pub fn reset_all_peaks(ctx: &Context) {
+        ctx.data_mut(|data| {
+            data.remove_by_type::<MeterState>();
+        });
+    }
 }
 
 impl<'a> Fader<'a> {
     /// Creates a fader with only one channel.
-    pub fn mono(level: &'a mut f32, signal: f32) -> Self {
+    pub fn mono<Num: emath::Numeric>(level: &'a mut Num, signal: f32) -> Self {
         Self::new(level, SignalKind::Mono(signal))
     }
 
     /// Creates a fader with two channels.
-    pub fn stereo(level: &'a mut f32, signal: [f32; 2]) -> Self {
+    pub fn stereo<Num: emath::Numeric>(level: &'a mut Num, signal: [f32; 2]) -> Self {
         Self::new(level, SignalKind::Stereo(signal))
     }
 
-    fn new(level: &'a mut f32, signal: SignalKind) -> Self {
+    /// Creates a fader for a DMX lighting channel.
+    /// Integer levels from 0 to 255 with a linear taper, percentage labels and no `NEG_INFINITY`
+    /// at the bottom of the fader.
+    pub fn dmx(level: &'a mut u8, signal: f32) -> Self {
+        Self::new(level, SignalKind::Mono(signal))
+            .increments(&DMX_INCREMENTS[..])
+            .neg_infinity_at_bottom(false)
+            .display_mode(DisplayMode::Percent)
+    }
+
+    /// Creates a mono fader from a closure getting and setting the level, like
+    /// [`egui::Slider::from_get_set`]. Useful when the level isn't a plain variable, e.g. a
+    /// parameter set by sending a message or through an undo stack.
+    ///
+    /// The closure is called with `Some(level)` to set the level and `None` to get it, and
+    /// returns the current level either way.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_params = std::collections::HashMap::from([("gain", -6.0_f32)]);
+    /// ui.add(egui_fader::Fader::from_get_set(
+    ///     |level: Option<f32>| {
+    ///         if let Some(level) = level {
+    ///             my_params.insert("gain", level);
+    ///         }
+    ///         my_params["gain"]
+    ///     },
+    ///     -20.0,
+    /// ));
+    /// # });
+    /// ```
+    pub fn from_get_set(get_set: impl 'a + FnMut(Option<f32>) -> f32, signal: f32) -> Self {
+        Self::with_get_set(get_set, SignalKind::Mono(signal))
+    }
+
+    /// Creates a stereo fader from a closure getting and setting the level, see
+    /// [`Self::from_get_set`].
+    pub fn stereo_from_get_set(
+        get_set: impl 'a + FnMut(Option<f32>) -> f32,
+        signal: [f32; 2],
+    ) -> Self {
+        Self::with_get_set(get_set, SignalKind::Stereo(signal))
+    }
+
+    /// Creates a mono fader bound to a level shared with another thread, e.g. the audio thread
+    /// of a plugin. The level is stored every time it changes.
+    pub fn atomic(level: &'a AtomicLevel, signal: f32) -> Self {
+        Self::from_get_set(
+            move |v: Option<f32>| {
+                if let Some(v) = v {
+                    level.store(v);
+                }
+                level.load()
+            },
+            signal,
+        )
+    }
+
+    fn with_get_set(mut get_set: impl 'a + FnMut(Option<f32>) -> f32, signal: SignalKind) -> Self {
+        let get_set_value = move |v: Option<f64>| get_set(v.map(|v| v as f32)) as f64;
+        Self::from_get_set_value(Box::new(get_set_value), false, signal)
+    }
+
+    fn new<Num: emath::Numeric>(level: &'a mut Num, signal: SignalKind) -> Self {
+        let get_set_value = move |v: Option<f64>| {
+            if let Some(v) = v {
+                *level = Num::from_f64(v);
+            }
+            level.to_f64()
+        };
+        Self::from_get_set_value(Box::new(get_set_value), Num::INTEGRAL, signal)
+    }
+
+    fn from_get_set_value(
+        get_set_value: GetSetValue<'a>,
+        integral: bool,
+        signal: SignalKind,
+    ) -> Self {
         Self {
-            level,
+            get_set_value,
+            integral,
+            normalized: false,
+            external_normalised: None,
             signal,
-            increments: vec![-100.0, -30.0, -10.0, 0.0, 10.0],
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            weighted_breakpoints: OnceCell::new(),
+            neg_infinity_at_bottom: true,
+            infinity_text: "-∞".to_owned(),
+            infinity_label: true,
+            bipolar: false,
             handle_shape: None,
+            handle_image: None,
+            skeuomorphic_handle: false,
+            handle_size: None,
+            rail_width: None,
+            meter_width: None,
             neutral_level: 0.0,
+            neutral_line: false,
+            show_labels: true,
+            show_text: true,
+            labels_only: false,
+            ticks: false,
+            minor_ticks: 1,
+            tick_lengths: [6.0, 3.0],
+            reference_lines: Cow::Borrowed(&[]),
+            scroll_step: 1.0,
+            key_step: 1.0,
+            page_step: 10.0,
+            jump_on_click: false,
+            interactive: true,
+            sense: Sense::drag(),
+            id_salt: None,
+            drag_anywhere: true,
+            drag_mode: DragMode::default(),
+            drag_conflict: DragConflict::default(),
+            drag_speed: 1.0,
+            fling: false,
+            commit_on_release: false,
+            on_gesture_start: None,
+            on_gesture_end: None,
+            selection: None,
+            group_drag_modifiers: Modifiers::SHIFT,
+            detent_modifiers: Modifiers::NONE,
+            quick_dip_modifiers: Modifiers::NONE,
+            quick_dip_momentary: false,
+            double_click_action: DoubleClickAction::default(),
+            out_of_range: OutOfRange::default(),
+            hit_margin: 0.0,
+            min_touch_target: 0.0,
+            context_menu: true,
+            context_menu_items: None,
+            tooltip: false,
+            tooltip_signal: false,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            coarse_drag_modifiers: Modifiers::NONE,
+            coarse_drag_ratio: FADER_COARSE_DRAG_RATIO,
             text_size: 10.0,
+            readout_font: None,
+            label_font: None,
+            channel_label_font: None,
+            channel_labels: [Cow::Borrowed("L"), Cow::Borrowed("R")],
+            style: FaderStyle::default(),
+            high_contrast: None,
+            reduced_motion: None,
+            accent_color: None,
+            frame: None,
+            overlay: None,
+            muted: false,
+            vca_offset: None,
+            overload_shading: false,
+            overload_level: 0.0,
+            invalid_signal: InvalidSignal::default(),
+            invalid_channels: [false; 2],
+            display_mode: DisplayMode::default(),
+            custom_formatter: None,
+            accessibility_text: None,
+            custom_parser: None,
+            label_formatter: None,
+            decimals: None,
+            suffix: String::new(),
+            suffix_on_labels: false,
             height: None,
+            width: None,
+            smoothing: None,
             peak_buffer_size: 60,
+            meter_repaint: None,
+            max_meter_refresh: None,
+            peak_marker: PeakMarker::default(),
+            peak_thickness: 2.0,
+            clip_indicator: false,
+            meter_state: None,
+            batch: None,
+            peak: None,
+            clip_lit: false,
+            #[cfg(feature = "accesskit")]
+            announcement: None,
         }
     }
 
@@ -120,243 +747,2204 @@ impl<'a> Fader<'a> {
     /// third from -10 to 0 and the final 25% from 0 to 10.
     /// By default, when the fader handle is at the bottom of the fader the value will be set to
     /// `NEG_INFINITY`. This does not need to be included in the intervals.
+    ///
+    /// Accepts an owned `Vec` or a borrowed slice, so one scale can be shared between many
+    /// faders without allocating per widget, e.g. by borrowing an `Arc<[f32]>` as `&scale[..]`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// let scale: std::sync::Arc<[f32]> = [-60.0, -20.0, 0.0, 6.0].into();
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).increments(&scale[..]));
+    /// # });
+    /// ```
     #[inline]
-    pub fn increments(mut self, increments: Vec<f32>) -> Self {
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        let increments = increments.into();
         debug_assert!(
             increments.is_sorted_by(|a, b| OrderedFloat(*a) < OrderedFloat(*b)),
             "Increments must be unique and in ascending order."
         );
         self.increments = increments;
+        self.weighted_breakpoints = OnceCell::new();
         self
     }
 
-    /// Set the neutral level that the fader handle will be set to when double clicked.
+    /// Set a relative weight for each segment between increments, giving some segments more of
+    /// the fader travel than others. There must be one weight per segment, i.e. one less than the
+    /// number of increments.
+    /// E.g. with the default increments the weights `[1, 1, 1, 3]` give the top segment `[0, 10]`
+    /// half of the fader.
     #[inline]
-    pub fn neutral_level(mut self, neutral_level: f32) -> Self {
-        self.neutral_level = neutral_level;
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        let segment_weights = segment_weights.into();
+        debug_assert!(
+            segment_weights.iter().all(|weight| *weight > 0.0),
+            "Segment weights must be positive."
+        );
+        self.segment_weights = Some(segment_weights);
+        self.weighted_breakpoints = OnceCell::new();
         self
     }
 
-    /// Set the shape of the fader handle to a circle shape.
-    /// The default value is set by `egui::Ui.style().visuals.handle_shape` but can be
-    /// overwritten for this widget here.
+    /// Make the fader bipolar, going from `-range` to `+range` with 0 in the centre.
+    /// The rail is filled from the centre toward the handle and double clicking returns to 0.
+    /// Useful for cut/boost parameters such as a ±12 dB trim.
+    ///
+    /// This sets symmetric increments, if they are overwritten afterwards they should stay
+    /// symmetric around the neutral level for it to remain centred.
+    ///
+    /// Panics if `range` is zero or not finite, which would leave the fader with no travel.
     #[inline]
-    pub fn circle_handle_shape(mut self) -> Self {
-        self.handle_shape = Some(HandleShape::Circle);
-        self
+    pub fn bipolar(self, range: f32) -> Self {
+        let range = range.abs();
+        assert!(
+            range > 0.0 && range.is_finite(),
+            "The bipolar range must be non-zero and finite."
+        );
+        let mut fader = self.increments(vec![-range, -0.5 * range, 0.0, 0.5 * range, range]);
+        fader.neutral_level = 0.0;
+        fader.neg_infinity_at_bottom = false;
+        fader.bipolar = true;
+        fader
     }
 
-    /// Set the shape of the fader handle to a rect shape with some aspect ratio..
-    /// The default value is set by `egui::Ui.style().visuals.handle_shape` but can be
-    /// overwritten for this widget here.
+    /// Set whether the bottom of the fader sets the level to `NEG_INFINITY`.
+    /// When disabled the bottom of the fader is the first increment.
+    /// Default: `true`.
     #[inline]
-    pub fn rect_handle_shape(mut self, aspect_ratio: f32) -> Self {
-        self.handle_shape = Some(HandleShape::Rect { aspect_ratio });
+    pub fn neg_infinity_at_bottom(mut self, neg_infinity_at_bottom: bool) -> Self {
+        self.neg_infinity_at_bottom = neg_infinity_at_bottom;
         self
     }
 
-    /// Set the size of the text displayed on the widget.
+    /// Set the text shown for a `NEG_INFINITY` level in the readout and the bottom label.
+    /// Default: `"-∞"`.
     #[inline]
-    pub fn text_size(mut self, text_size: f32) -> Self {
-        self.text_size = text_size;
+    pub fn infinity_text(mut self, infinity_text: impl ToString) -> Self {
+        self.infinity_text = infinity_text.to_string();
         self
     }
 
-    /// Set the number of frames that will be stored in the peak buffer.
+    /// Set whether the bottom scale label shows the [`Self::infinity_text`] instead of the first
+    /// increment when [`Self::neg_infinity_at_bottom`] is enabled.
+    /// Default: `true`.
     #[inline]
-    pub fn peak_buffer_size(mut self, peak_buffer_size: usize) -> Self {
-        self.peak_buffer_size = peak_buffer_size;
+    pub fn infinity_label(mut self, infinity_label: bool) -> Self {
+        self.infinity_label = infinity_label;
         self
     }
 
-    fn set_level(&mut self, level: f32) {
-        *self.level = level
+    /// Read and write the level as a normalized `0..=1` value instead of in increment units.
+    /// The increments are still used for the labels and readout, the mapping is only applied
+    /// for display. Useful for plugin parameters which are stored normalized.
+    #[inline]
+    pub fn normalized(mut self, normalized: bool) -> Self {
+        self.normalized = normalized;
+        self
     }
 
-    fn get_level(&self) -> f32 {
-        *self.level
+    /// Drive the fader from an incoming 7-bit MIDI CC value (0–127).
+    /// Pass `Some` only on frames where a new CC value arrived, the level is set from it using the
+    /// same mapping as [`midi::level_from_cc7`].
+    #[inline]
+    pub fn midi_cc7(mut self, cc: Option<u8>) -> Self {
+        self.external_normalised = cc.map(midi::normalised_from_cc7);
+        self
     }
 
-    fn handle_radius(&self, rect: &Rect) -> f32 {
-        rect.width() / 2.5
+    /// Drive the fader from an incoming 14-bit MIDI CC value (0–16383).
+    /// Pass `Some` only on frames where a new CC value arrived, the level is set from it using the
+    /// same mapping as [`midi::level_from_cc14`].
+    #[inline]
+    pub fn midi_cc14(mut self, cc: Option<u16>) -> Self {
+        self.external_normalised = cc.map(midi::normalised_from_cc14);
+        self
     }
 
-    fn handle_shape(&self, ui: &Ui) -> HandleShape {
-        self.handle_shape
-            .unwrap_or_else(|| ui.style().visuals.handle_shape)
+    /// Set the neutral level that the fader handle will be set to when double clicked.
+    #[inline]
+    pub fn neutral_level(mut self, neutral_level: f32) -> Self {
+        self.neutral_level = neutral_level;
+        self
     }
 
-    fn set_to_neutral(&mut self) {
-        let min = self.increments[0];
-        let max = self.increments[self.increments.len() - 1];
-        self.set_level(self.neutral_level.clamp(min, max))
+    /// Draw a reference line across the rail at the neutral level, e.g. to mark unity gain.
+    #[inline]
+    pub fn neutral_line(mut self, neutral_line: bool) -> Self {
+        self.neutral_line = neutral_line;
+        self
     }
 
-    fn position_range(&self, rect: &Rect, handle_shape: &HandleShape) -> Rangef {
-        let handle_radius = self.handle_radius(rect);
-        let handle_radius = match handle_shape {
-            HandleShape::Circle => handle_radius,
-            HandleShape::Rect { aspect_ratio } => handle_radius * aspect_ratio,
-        };
-        rect.y_range().shrink(handle_radius).flip()
+    /// Set whether the column of increment labels is shown. Default: `true`.
+    /// Without it the fader is narrower, e.g. for a [`FaderBank`] sharing one scale column.
+    #[inline]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
     }
 
-    fn value_from_position(&self, position: f32, position_range: Rangef) -> f32 {
-        let normalised = remap_clamp(position, position_range, 0.0..=1.0);
-        value_from_normalised(normalised, self.increments.clone())
+    /// Set whether any text is drawn. Without it there is no readout, increment labels or
+    /// channel letters, and typing a level is unavailable, for the cheapest possible meters on
+    /// dense overview pages. Default: `true`.
+    #[inline]
+    pub fn show_text(mut self, show_text: bool) -> Self {
+        self.show_text = show_text;
+        self
     }
 
-    fn position_from_value(&self, value: f32, position_range: Rangef) -> f32 {
-        let normalised = normalised_from_value(value, self.increments.clone());
-        lerp(position_range, normalised)
+    /// Only draw the increment labels, lined up with faders configured the same way.
+    #[inline]
+    pub(crate) fn labels_only(mut self) -> Self {
+        self.labels_only = true;
+        self
     }
 
-    fn text_padding(&self) -> f32 {
-        self.text_size * 0.25
+    /// Draw tick marks beside the rail, major ticks at the increments and minor ticks between
+    /// them.
+    #[inline]
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
     }
 
-    // Keep peak shape within the channel.
-    fn clamp_peak(&self, rect: &Rect, peak: f32, radius: f32) -> f32 {
-        peak.clamp(rect.top() + radius, rect.bottom() - radius)
+    /// Set the number of minor ticks evenly spaced between each pair of increments. Default: `1`.
+    #[inline]
+    pub fn minor_ticks(mut self, minor_ticks: usize) -> Self {
+        self.minor_ticks = minor_ticks;
+        self
     }
 
-    /// The interactive element of the fader.
-    fn fader_interaction(&mut self, ui: &Ui, response: &Response) {
-        if response.interact(Sense::click()).double_clicked() {
-            self.set_to_neutral();
-        };
-        let rect = &response.rect;
-        let handle_shape = self.handle_shape(ui);
-        let position_range = self.position_range(rect, &handle_shape);
-
-        if response.dragged() {
-            let mut delta = response.drag_delta().y;
-            ui.input(|input| {
-                if input.modifiers.ctrl || input.modifiers.shift || input.modifiers.alt {
-                    delta *= FADER_FINE_DRAG_RATIO
-                };
-            });
-            let centre = self
-                .position_from_value(self.get_level(), self.position_range(rect, &handle_shape));
-            let new_value = self.value_from_position(centre + delta, position_range);
-            self.set_level(new_value)
-        }
+    /// Set the length of the major and minor ticks in points. Default: `6.0` and `3.0`.
+    #[inline]
+    pub fn tick_lengths(mut self, major: f32, minor: f32) -> Self {
+        self.tick_lengths = [major, minor];
+        self
     }
 
-    fn fader_ui(&mut self, ui: &Ui, response: &Response) {
-        // Shrink rect to allow for text underneath.
-        let rect = response.rect;
-        let bottom_padding = self.text_size + self.text_padding();
-        let rect = rect
-            .shrink2(vec2(0.0, bottom_padding))
-            .translate(vec2(0.0, -bottom_padding * 0.5));
-
-        // Divide response into three sections.
-        let (left, right) = rect.split_left_right_at_fraction(1.0 / 5.0);
-        let (middle, right) = right.split_left_right_at_fraction(0.5);
-        let rail_response = response.clone().with_new_rect(left);
-        self.fader_interaction(ui, &rail_response);
-        self.rail_ui(ui, &rail_response);
-        self.label_ui(ui, middle, &rail_response);
-        self.signal_ui(ui, right, &rail_response);
+    /// Draw extra reference lines across the rail at these levels.
+    #[inline]
+    pub fn reference_lines(mut self, reference_lines: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.reference_lines = reference_lines.into();
+        self
     }
 
-    fn rail_ui(&self, ui: &Ui, response: &Response) {
-        // Rail for fader handle.
-        let visuals = ui.style().interact(response);
-        let rect = response.rect;
-        let rail_radius = ui.spacing().slider_rail_height * 0.5;
-        let rail_rect = Rect::from_min_max(
-            pos2(rect.center().x - rail_radius, rect.top()),
-            pos2(rect.center().x + rail_radius, rect.bottom()),
-        );
-        let rail_corner = ui.visuals().widgets.inactive.corner_radius;
-        let rail_style = ui.visuals().widgets.inactive.bg_fill;
-        ui.painter().rect_filled(rail_rect, rail_corner, rail_style);
-
-        // Fader handle.
-        let handle_radius = self.handle_radius(&rect);
-        let handle_shape = self.handle_shape(ui);
-        let center = pos2(
-            rect.center().x,
-            self.position_from_value(self.get_level(), self.position_range(&rect, &handle_shape)),
-        );
+    /// Set how much the mouse wheel changes the level by when the fader is hovered.
+    /// Holding a fine or coarse drag modifier scales the step. Set to 0 to disable scrolling.
+    /// Default: `1.0`.
+    #[inline]
+    pub fn scroll_step(mut self, scroll_step: f32) -> Self {
+        self.scroll_step = scroll_step;
+        self
+    }
 
-        match handle_shape {
-            HandleShape::Circle => {
-                ui.painter().add(epaint::CircleShape {
-                    center,
-                    radius: handle_radius + visuals.expansion,
-                    fill: visuals.bg_fill,
-                    stroke: visuals.fg_stroke,
-                });
-            }
-            HandleShape::Rect { aspect_ratio } => {
-                let v = Vec2::new(handle_radius, handle_radius * aspect_ratio);
-                let v = v + Vec2::splat(visuals.expansion);
-                let rect = Rect::from_center_size(center, 2.0 * v);
-                ui.painter().rect(
-                    rect,
-                    visuals.corner_radius,
-                    visuals.bg_fill,
-                    visuals.fg_stroke,
-                    epaint::StrokeKind::Inside,
-                );
-            }
-        }
+    /// Set how much the up and down arrow keys change the level by when the fader has focus.
+    /// Holding a fine or coarse drag modifier scales the step.
+    /// Default: `1.0`.
+    #[inline]
+    pub fn key_step(mut self, key_step: f32) -> Self {
+        self.key_step = key_step;
+        self
+    }
 
-        // Level text
-        let level_text = format!("{:.1}", self.get_level());
-        let text_pos = rect.center_bottom() + vec2(0.0, self.text_padding());
-        let text_anchor = Align2::CENTER_TOP;
-        let font_id = FontId::proportional(self.text_size);
-        let text_colour = ui.style().visuals.text_color();
-        ui.painter()
-            .text(text_pos, text_anchor, level_text, font_id, text_colour);
+    /// Set how much the page up and page down keys change the level by when the fader has focus.
+    /// Default: `10.0`.
+    #[inline]
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = page_step;
+        self
     }
 
-    fn label_ui(&self, ui: &Ui, rect: Rect, rail_response: &Response) {
-        let rail_rect = &rail_response.rect;
-        let handle_shape = self.handle_shape(ui);
-        let text_anchor = Align2::CENTER_CENTER;
-        let text_colour = ui.style().visuals.text_color();
-        for value in self.increments.clone() {
-            let mut font_id = FontId::proportional(self.text_size);
-            let text_y =
-                self.position_from_value(value, self.position_range(rail_rect, &handle_shape));
-            let text_pos = pos2(rect.center().x, text_y);
-            let text = if value == *self.increments.first().unwrap() {
-                // Account for the small infinity symbol.
-                font_id = FontId::proportional(self.text_size * 1.5);
-                "-∞".to_string()
-            } else {
-                format!("{value}")
-            };
-            ui.painter()
-                .text(text_pos, text_anchor, text, font_id.clone(), text_colour);
-        }
+    /// Set whether a single click on the rail moves the handle to the clicked position.
+    /// Default: `false`.
+    #[inline]
+    pub fn jump_on_click(mut self, jump_on_click: bool) -> Self {
+        self.jump_on_click = jump_on_click;
+        self
     }
 
-    fn channel_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
-        let corner = ui.style().visuals.widgets.inactive.corner_radius;
-        let colour = ui.style().visuals.faint_bg_color;
-        (corner, colour)
+    /// Set whether the fader can be changed by the user.
+    /// A non-interactive fader still shows the level and live signal but ignores all input and
+    /// uses the disabled visuals, the same as adding it with [`Ui::add_enabled`].
+    /// Default: `true`.
+    #[inline]
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
     }
 
-    fn signal_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
-        let corner = ui.style().visuals.widgets.inactive.corner_radius;
-        let colour = ui.style().visuals.widgets.active.fg_stroke.color;
-        (corner, colour)
+    /// Set the [`Sense`] the fader is allocated with.
+    /// E.g. use [`Sense::click()`] so the fader doesn't take drags from a surrounding
+    /// drag-and-drop container. Without drag sensing the level can still be changed with clicks,
+    /// the scroll wheel and the keyboard.
+    /// Default: [`Sense::drag()`].
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
     }
 
-    fn peak_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
-        let corner = ui.style().visuals.widgets.active.corner_radius;
-        let colour = ui.style().visuals.widgets.inactive.fg_stroke.color;
-        (corner, colour)
+    /// Set a source for the id of the fader, unique within the parent [`Ui`].
+    /// The peak hold, smoothing and drag state are stored under this id, so set it (e.g. to a
+    /// channel number) when faders are re-ordered or conditionally shown, otherwise the state
+    /// follows the position in the layout rather than the channel.
+    /// The id can be recreated with [`Ui::make_persistent_id`], e.g. for
+    /// [`Fader::drag_start_level`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
     }
 
-    fn channel_radius(&self, ui: &Ui) -> f32 {
-        ui.spacing().slider_rail_height * 0.5
+    /// Set whether the whole widget, including the labels and signal meter, can be dragged and
+    /// clicked to move the fader. Turn it off to only respond on the rail, e.g. to keep drags on
+    /// the meter free for something else.
+    /// Default: `true`.
+    #[inline]
+    pub fn drag_anywhere(mut self, drag_anywhere: bool) -> Self {
+        self.drag_anywhere = drag_anywhere;
+        self
+    }
+
+    /// Set whether dragging moves the level relative to where the drag started or sets it from
+    /// the absolute pointer position.
+    /// Default: [`DragMode::Relative`].
+    #[inline]
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// Set what happens when the bound level is changed from outside during a drag.
+    /// Has no effect with [`Self::commit_on_release`], which doesn't read the bound level until
+    /// the drag ends.
+    /// Default: [`DragConflict::Relative`].
+    #[inline]
+    pub fn drag_conflict(mut self, drag_conflict: DragConflict) -> Self {
+        self.drag_conflict = drag_conflict;
+        self
+    }
+
+    /// Set the multiplier applied to the distance dragged, independent of the fader height.
+    /// Values below 1 make small faders less twitchy. Only applies to [`DragMode::Relative`].
+    /// Default: `1.0`.
+    #[inline]
+    pub fn drag_speed(mut self, drag_speed: f32) -> Self {
+        self.drag_speed = drag_speed;
+        self
+    }
+
+    /// Set whether a fast touch drag keeps gliding briefly after release.
+    /// Mouse drags are unaffected.
+    /// Default: `false`.
+    #[inline]
+    pub fn fling(mut self, fling: bool) -> Self {
+        self.fling = fling;
+        self
+    }
+
+    /// Set whether a drag only writes the level when it ends. The handle and readout follow the
+    /// drag, but the bound level keeps its value until release, for engines that can't take a
+    /// parameter change every frame. Clicks, scrolling and keys still write immediately.
+    /// Default: `false`.
+    #[inline]
+    pub fn commit_on_release(mut self, commit_on_release: bool) -> Self {
+        self.commit_on_release = commit_on_release;
+        self
+    }
+
+    /// Call `on_gesture_start` with the level when the user starts changing it: when a drag
+    /// starts, and just before a double click resets the level. Maps to a plugin host's
+    /// `begin_edit` or touch automation. Every start is followed by an end.
+    #[inline]
+    pub fn on_gesture_start(mut self, on_gesture_start: impl 'a + FnMut(f64)) -> Self {
+        self.on_gesture_start = Some(Box::new(on_gesture_start));
+        self
+    }
+
+    /// Call `on_gesture_end` with the new level when the user stops changing it: when a drag
+    /// ends, after any deferred level is written, and just after a double click resets the
+    /// level. Maps to a plugin host's `end_edit`.
+    #[inline]
+    pub fn on_gesture_end(mut self, on_gesture_end: impl 'a + FnMut(f64)) -> Self {
+        self.on_gesture_end = Some(Box::new(on_gesture_end));
+        self
+    }
+
+    /// Make this fader the one at `index` in a [`FaderSelection`]. Command clicking the fader
+    /// selects or deselects it, and shift dragging it while selected moves the rest of the
+    /// selection, see [`Self::group_drag_modifiers`].
+    #[inline]
+    pub fn selection(mut self, selection: &'a mut FaderSelection, index: usize) -> Self {
+        self.selection = Some((selection, index));
+        self
+    }
+
+    /// Set the modifiers that make a drag of a selected fader move the rest of its
+    /// [`Self::selection`], holding any one of them is enough. While held they don't also
+    /// change the drag speed, so shift still fine adjusts a fader that isn't selected.
+    /// Use [`Modifiers::NONE`] to always drag the selection together.
+    /// Default: shift.
+    #[inline]
+    pub fn group_drag_modifiers(mut self, group_drag_modifiers: Modifiers) -> Self {
+        self.group_drag_modifiers = group_drag_modifiers;
+        self
+    }
+
+    /// Set the modifiers that constrain dragging to the increments, holding any one of them is
+    /// enough. While held the level steps between the increments and the bottom of the fader.
+    /// Default: [`Modifiers::NONE`], detents are disabled.
+    #[inline]
+    pub fn detent_modifiers(mut self, detent_modifiers: Modifiers) -> Self {
+        self.detent_modifiers = detent_modifiers;
+        self
+    }
+
+    /// Set the modifiers that make a click dip the level to the bottom of the fader
+    /// (`NEG_INFINITY` unless disabled), holding any one of them is enough. Another modifier
+    /// click restores the previous level, see also [`Self::quick_dip_momentary`].
+    /// Default: [`Modifiers::NONE`], quick dip is disabled.
+    #[inline]
+    pub fn quick_dip_modifiers(mut self, quick_dip_modifiers: Modifiers) -> Self {
+        self.quick_dip_modifiers = quick_dip_modifiers;
+        self
+    }
+
+    /// Set whether the quick dip only lasts while the pointer is held down, restoring the
+    /// previous level on release.
+    /// Default: `false`.
+    #[inline]
+    pub fn quick_dip_momentary(mut self, quick_dip_momentary: bool) -> Self {
+        self.quick_dip_momentary = quick_dip_momentary;
+        self
+    }
+
+    /// Set what double clicking the fader does.
+    /// Default: [`DoubleClickAction::Neutral`].
+    #[inline]
+    pub fn double_click_action(mut self, double_click_action: DoubleClickAction) -> Self {
+        self.double_click_action = double_click_action;
+        self
+    }
+
+    /// Set what the fader does with a level outside its increments.
+    /// Default: [`OutOfRange::ClampDisplay`].
+    #[inline]
+    pub fn out_of_range(mut self, out_of_range: OutOfRange) -> Self {
+        self.out_of_range = out_of_range;
+        self
+    }
+
+    /// Expand the interactive area by this many points on every side without changing how the
+    /// fader is drawn. Useful for finger-sized targets on touch screens.
+    /// Default: `0.0`.
+    #[inline]
+    pub fn hit_margin(mut self, hit_margin: f32) -> Self {
+        self.hit_margin = hit_margin;
+        self
+    }
+
+    /// Grow the interactive area of the rail so the handle can be grabbed in a square at least
+    /// this many points across, however small the fader is drawn. Apple and Google both suggest
+    /// around `44.0` for touch screens. Works on top of [`Fader::hit_margin`].
+    /// Default: `0.0`.
+    #[inline]
+    pub fn min_touch_target(mut self, min_touch_target: f32) -> Self {
+        self.min_touch_target = min_touch_target;
+        self
+    }
+
+    /// Set whether right clicking the fader opens a context menu to reset the level, set it to
+    /// `NEG_INFINITY` or type in a value.
+    /// Default: `true`.
+    #[inline]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = context_menu;
+        self
+    }
+
+    /// Add extra entries to the end of the context menu.
+    /// This also enables the context menu.
+    #[inline]
+    pub fn context_menu_items(mut self, add_contents: impl 'a + FnOnce(&mut Ui)) -> Self {
+        self.context_menu = true;
+        self.context_menu_items = Some(Box::new(add_contents));
+        self
+    }
+
+    /// Set whether a tooltip with the precise level is shown while hovering or dragging.
+    /// Default: `false`.
+    #[inline]
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.tooltip = tooltip;
+        self
+    }
+
+    /// Set whether the tooltip also shows the current signal and peak of each channel.
+    /// This also enables the tooltip.
+    /// Default: `false`.
+    #[inline]
+    pub fn tooltip_signal(mut self, tooltip_signal: bool) -> Self {
+        self.tooltip |= tooltip_signal;
+        self.tooltip_signal = tooltip_signal;
+        self
+    }
+
+    /// Set the modifiers that enable fine adjustment, holding any one of them is enough.
+    /// Use [`Modifiers::NONE`] to disable fine adjustment.
+    /// Default: ctrl, shift or alt.
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, fine_drag_modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = fine_drag_modifiers;
+        self
+    }
+
+    /// Set the ratio that dragging, scrolling and arrow keys are scaled by during fine adjustment.
+    /// Default: `0.2`.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, fine_drag_ratio: f32) -> Self {
+        self.fine_drag_ratio = fine_drag_ratio;
+        self
+    }
+
+    /// Set the modifiers that enable coarse adjustment for quickly sweeping across the range,
+    /// holding any one of them is enough. Coarse adjustment takes priority over fine adjustment
+    /// when both share a modifier.
+    /// Default: [`Modifiers::NONE`], coarse adjustment is disabled.
+    #[inline]
+    pub fn coarse_drag_modifiers(mut self, coarse_drag_modifiers: Modifiers) -> Self {
+        self.coarse_drag_modifiers = coarse_drag_modifiers;
+        self
+    }
+
+    /// Set the ratio that dragging, scrolling and arrow keys are scaled by during coarse
+    /// adjustment.
+    /// Default: `5.0`.
+    #[inline]
+    pub fn coarse_drag_ratio(mut self, coarse_drag_ratio: f32) -> Self {
+        self.coarse_drag_ratio = coarse_drag_ratio;
+        self
+    }
+
+    /// Set the shape of the fader handle to a circle shape.
+    /// The default value is set by `egui::Ui.style().visuals.handle_shape` but can be
+    /// overwritten for this widget here.
+    #[inline]
+    pub fn circle_handle_shape(mut self) -> Self {
+        self.handle_shape = Some(HandleShape::Circle);
+        self
+    }
+
+    /// Set the shape of the fader handle to a rect shape with some aspect ratio..
+    /// The default value is set by `egui::Ui.style().visuals.handle_shape` but can be
+    /// overwritten for this widget here.
+    #[inline]
+    pub fn rect_handle_shape(mut self, aspect_ratio: f32) -> Self {
+        self.handle_shape = Some(HandleShape::Rect { aspect_ratio });
+        self
+    }
+
+    /// Set the height of the fader in points.
+    /// The default is 1.5 times `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width of the fader with its labels in points, without labels it is 0.6 times
+    /// this. The default is 2 times `egui::Ui.spacing().interact_size.x`, or twice the height
+    /// of body text if that is larger.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the width of the fader handle in points, for rect handles the height follows from the
+    /// aspect ratio.
+    /// By default the handle is sized relative to the width of the rail area.
+    #[inline]
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = Some(handle_size);
+        self
+    }
+
+    /// Set the thickness of the rail in points.
+    /// The default value is set by `egui::Ui.spacing().slider_rail_height` but can be
+    /// overwritten for this widget here.
+    #[inline]
+    pub fn rail_width(mut self, rail_width: f32) -> Self {
+        self.rail_width = Some(rail_width);
+        self
+    }
+
+    /// Set the thickness of each signal meter channel in points.
+    /// The default value is set by `egui::Ui.spacing().slider_rail_height` but can be
+    /// overwritten for this widget here, e.g. to stop stereo meters overlapping on narrow faders.
+    #[inline]
+    pub fn meter_width(mut self, meter_width: f32) -> Self {
+        self.meter_width = Some(meter_width);
+        self
+    }
+
+    /// Draw the fader handle with a texture instead of the circle or rect shape.
+    /// The image is scaled to the size of the handle, so use [`Self::rect_handle_shape`] to
+    /// match the aspect ratio of the image.
+    #[inline]
+    pub fn handle_image(mut self, texture_id: TextureId) -> Self {
+        self.handle_image = Some(texture_id);
+        self
+    }
+
+    /// Draw the handle like a mixing console fader cap, with a gradient fill, a groove across
+    /// the centre and a drop shadow. The cap is always rectangular, a circular handle shape uses
+    /// an aspect ratio of 0.5.
+    #[inline]
+    pub fn skeuomorphic_handle(mut self, skeuomorphic_handle: bool) -> Self {
+        self.skeuomorphic_handle = skeuomorphic_handle;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the font of the level readout and value entry, e.g. a monospace font so the digits
+    /// don't jitter. Defaults to a proportional font of the [`Self::text_size`].
+    #[inline]
+    pub fn readout_font(mut self, font_id: FontId) -> Self {
+        self.readout_font = Some(font_id);
+        self
+    }
+
+    /// Set the font of the increment labels. Defaults to a proportional font of the
+    /// [`Self::text_size`].
+    #[inline]
+    pub fn label_font(mut self, font_id: FontId) -> Self {
+        self.label_font = Some(font_id);
+        self
+    }
+
+    /// Set the font of the left and right channel labels. Defaults to a proportional font of
+    /// the [`Self::text_size`].
+    #[inline]
+    pub fn channel_label_font(mut self, font_id: FontId) -> Self {
+        self.channel_label_font = Some(font_id);
+        self
+    }
+
+    /// Set the labels under the meter channels of a stereo fader, e.g. `["1", "2"]` or
+    /// `["In", "Out"]`. Empty labels are not shown.
+    /// Default: `["L", "R"]`.
+    #[inline]
+    pub fn channel_labels(mut self, channel_labels: [impl Into<Cow<'a, str>>; 2]) -> Self {
+        self.channel_labels = channel_labels.map(Into::into);
+        self
+    }
+
+    /// Set the colours of the fader, overriding those from the `Ui` visuals.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set whether the fader uses [`FaderStyle::high_contrast`] and a thicker peak line.
+    /// Colours set with [`Self::style`] still take priority.
+    /// Default: follow [`Fader::set_high_contrast`], which is off.
+    #[inline]
+    pub fn high_contrast(mut self, high_contrast: bool) -> Self {
+        self.high_contrast = Some(high_contrast);
+        self
+    }
+
+    /// Set whether the fader avoids motion: the handle jumps straight to new levels without
+    /// [`Self::smoothing`] or [`Self::fling`], and the peak shows the instantaneous signal
+    /// rather than holding.
+    /// Default: follow [`Fader::set_reduced_motion`], which is off.
+    #[inline]
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+
+    /// Colour code the fader by tinting the handle, signal bar and bipolar fill.
+    /// The handle keeps its hover and drag feedback from the `Ui` visuals, and colours set in
+    /// the [`FaderStyle`] take priority.
+    #[inline]
+    pub fn accent_color(mut self, accent_color: Color32) -> Self {
+        self.accent_color = Some(accent_color);
+        self
+    }
+
+    /// Paint a frame behind the whole fader, e.g. to separate channel strips. The fader grows by
+    /// the margins of the frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// let frame = egui::Frame::group(ui.style()).inner_margin(4);
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).frame(frame));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Shade the meter channels above the [`Self::overload_level`], so the danger zone is visible
+    /// even without a signal.
+    #[inline]
+    pub fn overload_shading(mut self, overload_shading: bool) -> Self {
+        self.overload_shading = overload_shading;
+        self
+    }
+
+    /// Set the level above which the meter is shaded when [`Self::overload_shading`] is enabled,
+    /// and above which the signal counts as clipped in [`FaderOutput::clipped`].
+    /// Default: `0.0`.
+    #[inline]
+    pub fn overload_level(mut self, overload_level: f32) -> Self {
+        self.overload_level = overload_level;
+        self
+    }
+
+    /// Set what the meter shows when the signal is NaN or `+∞`.
+    /// Default: [`InvalidSignal::Silence`].
+    #[inline]
+    pub fn invalid_signal(mut self, invalid_signal: InvalidSignal) -> Self {
+        self.invalid_signal = invalid_signal;
+        self
+    }
+
+    /// Paint extras on top of the fader, e.g. automation points or threshold lines. The painter
+    /// receives the areas of the fader and its state, which maps levels to positions.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).overlay(|painter, rects, state| {
+    ///     let y = state.y_from_level(-18.0);
+    ///     painter.hline(rects.meter.x_range(), y, (1.0, egui::Color32::RED));
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn overlay(mut self, overlay: impl 'a + Fn(&Painter, &FaderRects, &FaderState)) -> Self {
+        self.overlay = Some(Box::new(overlay));
+        self
+    }
+
+    /// Show the fader as muted, dimming the meter, greying the handle and showing an "M" badge.
+    /// This is only visual, the level can still be changed.
+    #[inline]
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    /// Show the offset of a VCA master, see [`FaderGroup`]. A ghost handle marks the effective
+    /// level, the handle and readout keep showing the fader's own level.
+    #[inline]
+    pub fn vca_offset(mut self, offset: f32) -> Self {
+        self.vca_offset = Some(offset);
+        self
+    }
+
+    /// Set how the level readout and increment labels are displayed.
+    /// The level mapping is unaffected, [`DisplayMode::Percent`] only changes the text.
+    #[inline]
+    pub fn display_mode(mut self, display_mode: DisplayMode) -> Self {
+        self.display_mode = display_mode;
+        self
+    }
+
+    /// Set custom formatter defining how the level readout is shown as text.
+    /// This takes priority over the [`DisplayMode`] for the readout.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).custom_formatter(|level| {
+    ///     if level == f64::NEG_INFINITY {
+    ///         "-∞ dB".to_owned()
+    ///     } else {
+    ///         format!("{level:.1} dB").replace('.', ",")
+    ///     }
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn custom_formatter(mut self, formatter: impl 'a + Fn(f64) -> String) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the text screen readers announce for a level, so they read e.g.
+    /// "Channel 3 level, minus 6.5 decibels" rather than a bare number.
+    /// Default: the readout text.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).accessibility_text(|level| {
+    ///     if level == f64::NEG_INFINITY {
+    ///         "Channel 3 level, silent".to_owned()
+    ///     } else {
+    ///         format!("Channel 3 level, {level:.1} decibels")
+    ///     }
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn accessibility_text(mut self, text: impl 'a + Fn(f64) -> String) -> Self {
+        self.accessibility_text = Some(Box::new(text));
+        self
+    }
+
+    /// Set custom parser defining how typed text is converted into a level when entering a
+    /// value. The result is clamped to the fader range, return `None` to reject the text.
+    #[inline]
+    pub fn custom_parser(mut self, parser: impl 'a + Fn(&str) -> Option<f64>) -> Self {
+        self.custom_parser = Some(Box::new(parser));
+        self
+    }
+
+    /// Set custom formatter defining how each scale label is shown.
+    /// The closure receives the increment and its index in the increments, labels that format
+    /// to an empty string are not shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).label_formatter(|value, index| {
+    ///     match value {
+    ///         0.0 => "U".to_owned(),
+    ///         _ if index % 2 == 1 => String::new(),
+    ///         _ => format!("{}", value.abs()),
+    ///     }
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn label_formatter(mut self, formatter: impl 'a + Fn(f32, usize) -> String) -> Self {
+        self.label_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Set the number of decimals shown in the level readout.
+    /// By default levels show one decimal and percentages none.
+    #[inline]
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Show a unit after the level readout, e.g. `.suffix(" dB")`.
+    /// The suffix is also ignored when parsing an entered value.
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Whether the [`Self::suffix`] is also shown on the scale labels. Default is false.
+    #[inline]
+    pub fn suffix_on_labels(mut self, suffix_on_labels: bool) -> Self {
+        self.suffix_on_labels = suffix_on_labels;
+        self
+    }
+
+    /// Glide the handle to a new level over this many seconds when the level is changed from
+    /// outside the fader, e.g. by automation or recalling a preset. The handle still follows the
+    /// pointer directly while dragging.
+    #[inline]
+    pub fn smoothing(mut self, seconds: f32) -> Self {
+        self.smoothing = Some(seconds);
+        self
+    }
+
+    /// Set the number of frames that will be stored in the peak buffer.
+    #[inline]
+    pub fn peak_buffer_size(mut self, peak_buffer_size: usize) -> Self {
+        self.peak_buffer_size = peak_buffer_size;
+        self
+    }
+
+    /// Request a repaint while the meter is visibly moving: when the signal or peak has moved by
+    /// more than `epsilon` points since the last frame, or a held peak is still to fall back to
+    /// the signal. An app then only needs to repaint when new signal arrives rather than every
+    /// frame. Off by default.
+    #[inline]
+    pub fn meter_repaint(mut self, epsilon: f32) -> Self {
+        self.meter_repaint = Some(epsilon);
+        self
+    }
+
+    /// Limit the repaints requested by [`Self::meter_repaint`] to this many per second.
+    #[inline]
+    pub fn max_meter_refresh(mut self, hz: f32) -> Self {
+        self.max_meter_refresh = Some(hz);
+        self
+    }
+
+    /// Set how the peak level is marked on the meter.
+    #[inline]
+    pub fn peak_marker(mut self, peak_marker: PeakMarker) -> Self {
+        self.peak_marker = peak_marker;
+        self
+    }
+
+    /// Set the thickness of the [`PeakMarker::Line`] in points. Default: `2.0`.
+    #[inline]
+    pub fn peak_thickness(mut self, peak_thickness: f32) -> Self {
+        self.peak_thickness = peak_thickness;
+        self
+    }
+
+    /// Show a clip light at the top of the meter. It lights once the signal goes above the
+    /// [`Self::overload_level`] and stays lit until it is clicked or [`Fader::reset_peaks`] is
+    /// called.
+    /// Default: `false`.
+    #[inline]
+    pub fn clip_indicator(mut self, clip_indicator: bool) -> Self {
+        self.clip_indicator = clip_indicator;
+        self
+    }
+
+    /// Keep the held peaks and clip light in `meter_state` rather than in egui memory, e.g. with
+    /// the rest of a channel's state. Use [`MeterState::reset`] in place of
+    /// [`Fader::reset_peaks`].
+    #[inline]
+    pub fn meter_state(mut self, meter_state: &'a mut MeterState) -> Self {
+        self.meter_state = Some(meter_state);
+        self
+    }
+
+    /// Fill the rail and meter into `mesh` rather than adding a shape for each part, without
+    /// their corner radii. The caller paints the mesh beneath the fader, see [`FaderBank`].
+    #[inline]
+    pub(crate) fn batch(mut self, mesh: &'a mut Mesh) -> Self {
+        self.batch = Some(RefCell::new(mesh));
+        self
+    }
+
+    /// Fill `rect`, or add it to the mesh when batched.
+    fn fill(&self, ui: &Ui, rect: Rect, corner: CornerRadius, colour: Color32) {
+        match &self.batch {
+            Some(mesh) => {
+                let colour = colour.gamma_multiply(ui.painter().opacity());
+                mesh.borrow_mut().add_colored_rect(rect, colour);
+            }
+            None => {
+                ui.painter().rect_filled(rect, corner, colour);
+            }
+        }
+    }
+
+    /// Fill a triangle, or add it to the mesh when batched.
+    fn fill_triangle(&self, ui: &Ui, points: [Pos2; 3], colour: Color32) {
+        match &self.batch {
+            Some(mesh) => {
+                let colour = colour.gamma_multiply(ui.painter().opacity());
+                let mut mesh = mesh.borrow_mut();
+                let index = mesh.vertices.len() as u32;
+                for point in points {
+                    mesh.colored_vertex(point, colour);
+                }
+                mesh.add_triangle(index, index + 1, index + 2);
+            }
+            None => {
+                ui.painter().add(epaint::PathShape::convex_polygon(
+                    points.to_vec(),
+                    colour,
+                    epaint::PathStroke::NONE,
+                ));
+            }
+        }
+    }
+
+    fn set_level(&mut self, level: f64) {
+        let level = if self.normalized {
+            self.normalise_level(level)
+        } else if self.integral {
+            // Integers have no `NEG_INFINITY`, use the bottom of the range instead.
+            level.max(self.increments[0] as f64).round()
+        } else {
+            level
+        };
+        set(&mut self.get_set_value, level)
+    }
+
+    fn get_level(&mut self) -> f64 {
+        let level = get(&mut self.get_set_value);
+        if self.normalized {
+            self.denormalise_level(level)
+        } else {
+            level
+        }
+    }
+
+    fn handle_radius(&self, rect: &Rect) -> f32 {
+        self.handle_size
+            .map_or_else(|| rect.width() / 2.5, |handle_size| handle_size * 0.5)
+    }
+
+    /// How far the interactive area reaches past `rect` on each side, enough for both the hit
+    /// margin and the minimum touch target around the handle on the rail.
+    fn touch_margin(&self, rect: Rect) -> Vec2 {
+        let rail_width = self.content_rect(rect).width() * self.rail_fraction();
+        let handle_radius =
+            self.handle_radius(&Rect::from_min_size(rect.min, vec2(rail_width, 0.0)));
+        let target = 0.5 * self.min_touch_target;
+        vec2(
+            (target - 0.5 * rail_width).max(self.hit_margin),
+            (target - handle_radius).max(self.hit_margin),
+        )
+    }
+
+    /// The share of the width taken by the rail column.
+    fn rail_fraction(&self) -> f32 {
+        if self.labels_shown() {
+            1.0 / 5.0
+        } else {
+            1.0 / 3.0
+        }
+    }
+
+    fn labels_shown(&self) -> bool {
+        self.show_labels && self.show_text
+    }
+
+    fn handle_shape(&self, ui: &Ui) -> HandleShape {
+        self.handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape)
+    }
+
+    fn set_to_neutral(&mut self) {
+        let min = self.increments[0];
+        let max = self.increments[self.increments.len() - 1];
+        self.set_level(self.neutral_level.clamp(min, max) as f64)
+    }
+
+    /// Move the level by `delta`, stepping off and back on to `NEG_INFINITY` at the bottom.
+    fn nudge_level(&mut self, delta: f32) {
+        let min = self.increments[0] as f64;
+        let max = self.increments[self.increments.len() - 1] as f64;
+        let level = self.get_level();
+        let delta = delta as f64;
+        let new_level = if level == f64::NEG_INFINITY {
+            if delta > 0.0 { min } else { level }
+        } else if level + delta < min {
+            self.denormalise_level(0.0)
+        } else if self.out_of_range == OutOfRange::Extend {
+            (level + delta).min(max.max(level))
+        } else {
+            (level + delta).min(max)
+        };
+        self.set_level(new_level)
+    }
+
+    fn position_range(&self, rect: &Rect, handle_shape: &HandleShape) -> Rangef {
+        let handle_radius = self.handle_radius(rect);
+        let handle_radius = match handle_shape {
+            HandleShape::Circle => handle_radius,
+            HandleShape::Rect { aspect_ratio } => handle_radius * aspect_ratio,
+        };
+        rect.y_range().shrink(handle_radius).flip()
+    }
+
+    fn weights(&self) -> Option<&[f32]> {
+        let weights = self.segment_weights.as_deref();
+        debug_assert!(
+            weights.is_none_or(|weights| weights.len() + 1 == self.increments.len()),
+            "There must be one segment weight per pair of increments."
+        );
+        weights
+    }
+
+    /// Normalised position of the increment at `index`.
+    fn breakpoint(&self, index: usize) -> f32 {
+        match self.weights() {
+            None => breakpoint(index, &self.increments, None),
+            Some(weights) => self
+                .weighted_breakpoints
+                .get_or_init(|| breakpoints(&self.increments, weights))[index],
+        }
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        self.normalise_level(value as f64) as f32
+    }
+
+    fn denormalise(&self, normalised: f32) -> f32 {
+        self.denormalise_level(normalised as f64) as f32
+    }
+
+    /// Like [`Self::normalise`] in `f64`, so an `f64` level keeps its precision.
+    fn normalise_level(&self, level: f64) -> f64 {
+        normalised_with_breakpoints(level, &self.increments, |index| self.breakpoint(index))
+    }
+
+    /// Like [`Self::denormalise`] in `f64`, so an `f64` level keeps its precision.
+    fn denormalise_level(&self, normalised: f64) -> f64 {
+        let level =
+            value_with_breakpoints(normalised, &self.increments, |index| self.breakpoint(index));
+        if level == f64::NEG_INFINITY && !self.neg_infinity_at_bottom {
+            self.increments[0] as f64
+        } else {
+            level
+        }
+    }
+
+    /// Normalised positions per unit of level along the top segment.
+    fn top_segment_rate(&self) -> f64 {
+        let last = self.increments.len() - 1;
+        let span = self.increments[last] as f64 - self.increments[last - 1] as f64;
+        (1.0 - self.breakpoint(last - 1) as f64) / span
+    }
+
+    /// Like [`Self::normalise_level`], but past `1.0` above the top increment with
+    /// [`OutOfRange::Extend`].
+    fn normalise_extended(&self, level: f64) -> f64 {
+        let top = self.increments[self.increments.len() - 1] as f64;
+        if self.out_of_range == OutOfRange::Extend && level > top {
+            1.0 + (level - top) * self.top_segment_rate()
+        } else {
+            self.normalise_level(level)
+        }
+    }
+
+    /// The inverse of [`Self::normalise_extended`].
+    fn denormalise_extended(&self, normalised: f64) -> f64 {
+        let top = self.increments[self.increments.len() - 1] as f64;
+        if self.out_of_range == OutOfRange::Extend && normalised > 1.0 {
+            top + (normalised - 1.0) / self.top_segment_rate()
+        } else {
+            self.denormalise_level(normalised)
+        }
+    }
+
+    fn value_from_position(&self, position: f32, position_range: Rangef) -> f64 {
+        let normalised = remap_clamp(position, position_range, 0.0..=1.0);
+        self.denormalise_level(normalised as f64)
+    }
+
+    /// The increment (or bottom of the fader) nearest to `position`.
+    fn snap_to_increment(&self, position: f32, position_range: Rangef) -> f32 {
+        let normalised = remap_clamp(position, position_range, 0.0..=1.0);
+        let nearest = (0..self.increments.len())
+            .map(|index| self.breakpoint(index))
+            .min_by_key(|breakpoint| OrderedFloat((breakpoint - normalised).abs()))
+            .unwrap_or_default();
+        self.denormalise(nearest)
+    }
+
+    fn position_from_value(&self, value: f32, position_range: Rangef) -> f32 {
+        let normalised = self.normalise(value);
+        lerp(position_range, normalised)
+    }
+
+    fn percent_from_value(&self, value: f32) -> f32 {
+        self.normalise(value) * 100.0
+    }
+
+    fn level_text(&self, level: f64) -> String {
+        let text = if let Some(formatter) = &self.custom_formatter {
+            formatter(level)
+        } else {
+            match self.display_mode {
+                DisplayMode::Level => {
+                    level_readout(level, &self.infinity_text, self.decimals.unwrap_or(1))
+                }
+                DisplayMode::Percent => {
+                    let decimals = self.decimals.unwrap_or(0);
+                    format!("{:.decimals$}%", self.percent_from_value(level as f32))
+                }
+            }
+        };
+        format!("{text}{}", self.suffix)
+    }
+
+    fn font(&self, font_id: &Option<FontId>) -> FontId {
+        font_id
+            .clone()
+            .unwrap_or_else(|| FontId::proportional(self.text_size))
+    }
+
+    fn text_padding(&self) -> f32 {
+        self.text_size * 0.25
+    }
+
+    // Keep peak shape within the channel.
+    fn clamp_peak(&self, rect: &Rect, peak: f32, radius: f32) -> f32 {
+        peak.clamp(rect.top() + radius, rect.bottom() - radius)
+    }
+
+    /// Whether a drag with these modifiers held moves the rest of the selection.
+    fn group_drag(&self, modifiers: Modifiers) -> bool {
+        self.selection
+            .as_ref()
+            .is_some_and(|(selection, index)| selection.is_selected(*index))
+            && (self.group_drag_modifiers.is_none()
+                || any_modifier_held(modifiers, self.group_drag_modifiers))
+    }
+
+    /// Select with a command click, share group drags with the rest of the selection and outline
+    /// the fader while selected.
+    fn selection_ui(&mut self, ui: &Ui, response: &Response, normalised_before: f32) {
+        if self.selection.is_none() {
+            return;
+        }
+        let level = self.get_level() as f32;
+        let normalised = self.normalise(level);
+        let group_drag = response.dragged() && self.group_drag(ui.input(|input| input.modifiers));
+        let Some((selection, index)) = &mut self.selection else {
+            return;
+        };
+        let click_response = response.clone().interact(Sense::click());
+        if click_response.clicked() && ui.input(|input| input.modifiers.command) {
+            selection.toggle(*index);
+        }
+        if group_drag {
+            selection.dragged(*index, normalised - normalised_before);
+        }
+        if selection.is_selected(*index) && ui.is_rect_visible(response.rect) {
+            ui.painter().rect_stroke(
+                response.rect,
+                ui.visuals().widgets.inactive.corner_radius,
+                ui.visuals().selection.stroke,
+                epaint::StrokeKind::Outside,
+            );
+        }
+    }
+
+    fn gesture_start(&mut self) {
+        let level = self.get_level();
+        if let Some(on_gesture_start) = &mut self.on_gesture_start {
+            on_gesture_start(level);
+        }
+    }
+
+    fn gesture_end(&mut self) {
+        let level = self.get_level();
+        if let Some(on_gesture_end) = &mut self.on_gesture_end {
+            on_gesture_end(level);
+        }
+    }
+
+    /// The interactive element of the fader.
+    /// `surface` is the area that responds to clicks and drags.
+    fn fader_interaction(&mut self, ui: &Ui, response: &Response, surface: Rect) {
+        let rect = &response.rect;
+        let handle_shape = self.handle_shape(ui);
+        let position_range = self.position_range(rect, &handle_shape);
+
+        let click_response = response
+            .clone()
+            .with_new_rect(surface)
+            .interact(Sense::click());
+        if self.quick_dip_interaction(ui, &click_response) {
+            return;
+        }
+        if click_response.double_clicked() {
+            match self.double_click_action {
+                DoubleClickAction::Neutral => {
+                    self.gesture_start();
+                    self.set_to_neutral();
+                    self.gesture_end();
+                }
+                DoubleClickAction::Bottom => {
+                    self.gesture_start();
+                    self.set_level(self.denormalise(0.0) as f64);
+                    self.gesture_end();
+                }
+                DoubleClickAction::EnterValue => self.start_editing(ui, response.id),
+                DoubleClickAction::None => {}
+            }
+        } else if self.jump_on_click
+            && click_response.clicked()
+            && let Some(pointer) = click_response.interact_pointer_pos()
+        {
+            let new_value = self.value_from_position(pointer.y, position_range);
+            self.set_level(new_value);
+        };
+
+        let pressed_on_surface = ui
+            .input(|input| input.pointer.press_origin())
+            .is_some_and(|origin| surface.contains(origin));
+        if response.drag_started() && pressed_on_surface {
+            let level = self.get_level();
+            ui.data_mut(|data| {
+                data.insert_temp(drag_start_id(response.id), level);
+                data.insert_temp(gesture_id(response.id), true);
+            });
+            self.gesture_start();
+        }
+        let dragging = response.dragged() && pressed_on_surface;
+        let drag_owned = self.drag_conflict_interaction(ui.ctx(), response.id, dragging);
+        // Only write back on movement so the level doesn't lose precision in the round trip.
+        let detent_id = response.id.with("detent");
+        let detent = ui.input(|input| any_modifier_held(input.modifiers, self.detent_modifiers));
+        if dragging && drag_owned && response.drag_delta().y != 0.0 {
+            // Move the normalised level in `f64` so an `f64` level keeps its precision.
+            let level = self.get_level();
+            let normalised = match self.drag_mode {
+                DragMode::Relative => {
+                    let delta =
+                        response.drag_delta().y * self.drag_speed * self.drag_adjustment_ratio(ui);
+                    let current = self.normalise_extended(level);
+                    // Snapping would undo small movements, so track the unsnapped position.
+                    let start = if detent {
+                        ui.data(|data| data.get_temp(detent_id)).unwrap_or(current)
+                    } else {
+                        current
+                    };
+                    Some(start + (delta / position_range.span()) as f64)
+                }
+                DragMode::Absolute => response
+                    .interact_pointer_pos()
+                    .map(|pointer| remap(pointer.y, position_range, 0.0..=1.0) as f64),
+            };
+            if let Some(normalised) = normalised {
+                let new_value = if detent {
+                    ui.data_mut(|data| data.insert_temp(detent_id, normalised));
+                    let position = lerp(position_range, normalised as f32);
+                    self.snap_to_increment(position, position_range) as f64
+                } else if self.out_of_range == OutOfRange::Extend {
+                    // Only ever drag down from a level past the top, never further up.
+                    let top = self.increments[self.increments.len() - 1] as f64;
+                    self.denormalise_extended(normalised.max(0.0))
+                        .min(level.max(top))
+                } else {
+                    self.denormalise_level(normalised.clamp(0.0, 1.0))
+                };
+                self.set_level(new_value)
+            }
+        }
+        if !detent || !response.dragged() {
+            ui.data_mut(|data| data.remove::<f64>(detent_id));
+        }
+        if dragging && drag_owned {
+            let owner = DragOwner::Drag(self.get_level());
+            ui.data_mut(|data| data.insert_temp(drag_owner_id(response.id), owner));
+        }
+
+        if self.fling && drag_owned {
+            self.fling_interaction(ui, response, pressed_on_surface, position_range);
+        }
+
+        if response.drag_started() && pressed_on_surface {
+            response.request_focus();
+        }
+        if response.has_focus() {
+            self.keyboard_interaction(ui, response);
+        }
+        #[cfg(feature = "accesskit")]
+        self.accesskit_interaction(ui, response);
+
+        if response.hovered() && self.scroll_step != 0.0 {
+            // Shift turns vertical scrolling horizontal, so use both axes.
+            let scroll = ui.input(|input| input.raw_scroll_delta.x + input.raw_scroll_delta.y);
+            if scroll != 0.0 {
+                let step = self.scroll_step * scroll.signum() * self.adjustment_ratio(ui);
+                self.nudge_level(step);
+                // Stop any parent scroll area from scrolling too.
+                ui.input_mut(|input| {
+                    input.raw_scroll_delta = Vec2::ZERO;
+                    input.smooth_scroll_delta = Vec2::ZERO;
+                });
+            }
+        }
+    }
+
+    /// Settle a change to the bound level from outside during a drag, see [`DragConflict`].
+    /// Returns whether the drag still has the level.
+    fn drag_conflict_interaction(&mut self, ctx: &Context, id: Id, dragging: bool) -> bool {
+        let owner_id = drag_owner_id(id);
+        let owner = ctx.data(|data| data.get_temp::<DragOwner>(owner_id));
+        if !dragging {
+            ctx.data_mut(|data| data.remove::<DragOwner>(owner_id));
+            return !matches!(owner, Some(DragOwner::External));
+        }
+        match owner {
+            Some(DragOwner::External) => false,
+            Some(DragOwner::Drag(level)) if level != self.get_level() => match self.drag_conflict {
+                DragConflict::Relative => {
+                    // Snap from the new level rather than the position the drag had reached.
+                    ctx.data_mut(|data| data.remove::<f64>(id.with("detent")));
+                    true
+                }
+                DragConflict::DragWins => {
+                    self.set_level(level);
+                    true
+                }
+                DragConflict::ExternalWins => {
+                    ctx.data_mut(|data| data.insert_temp(owner_id, DragOwner::External));
+                    false
+                }
+            },
+            _ => true,
+        }
+    }
+
+    /// Track the velocity of touch drags and keep moving after release, slowing to a stop.
+    fn fling_interaction(
+        &mut self,
+        ui: &Ui,
+        response: &Response,
+        pressed_on_surface: bool,
+        position_range: Rangef,
+    ) {
+        let id = response.id.with("fling");
+        let mut fling = ui
+            .data(|data| data.get_temp::<Fling>(id))
+            .unwrap_or_default();
+        if response.drag_started() && pressed_on_surface {
+            fling.touch = ui.input(|input| input.any_touches());
+        }
+        if response.dragged() {
+            fling.velocity = if fling.touch && pressed_on_surface {
+                ui.input(|input| input.pointer.velocity().y) * self.drag_speed
+            } else {
+                0.0
+            };
+        } else if fling.velocity.abs() > FLING_MIN_VELOCITY {
+            let dt = ui.input(|input| input.stable_dt);
+            let step = (fling.velocity * dt / position_range.span()) as f64;
+            let level = self.get_level();
+            let normalised = (self.normalise_level(level) + step).clamp(0.0, 1.0);
+            self.set_level(self.denormalise_level(normalised));
+            fling.velocity *= (-FLING_FRICTION * dt).exp();
+            // Stop at the ends of the fader.
+            if normalised <= 0.0 || normalised >= 1.0 {
+                fling.velocity = 0.0;
+            }
+            ui.ctx().request_repaint();
+        }
+        ui.data_mut(|data| data.insert_temp(id, fling));
+    }
+
+    /// Dip the level to the bottom of the fader with a modifier click and restore it again.
+    /// Returns whether the interaction was used by the quick dip.
+    fn quick_dip_interaction(&mut self, ui: &Ui, response: &Response) -> bool {
+        let id = response.id.with("dip");
+        let held = ui.input(|input| any_modifier_held(input.modifiers, self.quick_dip_modifiers));
+        let dipped_from = ui.data(|data| data.get_temp::<f64>(id));
+        let dip = |fader: &mut Self| {
+            let level = fader.get_level();
+            ui.data_mut(|data| data.insert_temp(id, level));
+            fader.set_level(fader.denormalise(0.0) as f64);
+        };
+        let restore = |fader: &mut Self, level: f64| {
+            ui.data_mut(|data| data.remove::<f64>(id));
+            fader.set_level(level);
+        };
+        if self.quick_dip_momentary {
+            match (response.is_pointer_button_down_on(), dipped_from) {
+                (true, None) if held => {
+                    dip(self);
+                    true
+                }
+                (true, Some(_)) => true,
+                (false, Some(level)) => {
+                    restore(self, level);
+                    true
+                }
+                _ => false,
+            }
+        } else if held && response.clicked() {
+            match dipped_from {
+                Some(level) => restore(self, level),
+                None => dip(self),
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn keyboard_interaction(&mut self, ui: &Ui, response: &Response) {
+        ui.memory_mut(|mem| {
+            mem.set_focus_lock_filter(
+                response.id,
+                EventFilter {
+                    // Arrows move the fader rather than focus.
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            )
+        });
+        let presses = ui.input(|input| {
+            input.num_presses(Key::ArrowUp) as f32 - input.num_presses(Key::ArrowDown) as f32
+        });
+        if presses != 0.0 {
+            let step = self.key_step * presses * self.adjustment_ratio(ui);
+            self.nudge_level(step);
+        }
+        let pages = ui.input(|input| {
+            input.num_presses(Key::PageUp) as f32 - input.num_presses(Key::PageDown) as f32
+        });
+        if pages != 0.0 {
+            self.nudge_level(self.page_step * pages);
+        }
+        // Home and End jump to the bottom and top of the fader.
+        if ui.input(|input| input.key_pressed(Key::Home)) {
+            self.set_level(self.denormalise(0.0) as f64);
+        }
+        if ui.input(|input| input.key_pressed(Key::End)) {
+            self.set_level(self.denormalise(1.0) as f64);
+        }
+        // Consume the key so the new text edit doesn't take it as committing the value.
+        if ui.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Enter)) {
+            self.start_editing(ui, response.id);
+        }
+    }
+
+    /// Actions requested by assistive technology.
+    #[cfg(feature = "accesskit")]
+    fn accesskit_interaction(&mut self, ui: &Ui, response: &Response) {
+        use egui::accesskit::{Action, ActionData};
+        let steps = ui.input(|input| {
+            input.num_accesskit_action_requests(response.id, Action::Increment) as f32
+                - input.num_accesskit_action_requests(response.id, Action::Decrement) as f32
+        });
+        if steps != 0.0 {
+            self.nudge_level(self.key_step * steps);
+        }
+        let values: Vec<f64> = ui.input(|input| {
+            input
+                .accesskit_action_requests(response.id, Action::SetValue)
+                .filter_map(|request| match request.data {
+                    Some(ActionData::NumericValue(value)) => Some(value),
+                    _ => None,
+                })
+                .collect()
+        });
+        for value in values {
+            self.set_level(self.clamp_level(value as f32));
+        }
+    }
+
+    /// Describe the fader to assistive technology as a slider.
+    fn accessibility_ui(&mut self, ui: &Ui, response: &Response) {
+        let level = self.get_level();
+        response.widget_info(|| WidgetInfo {
+            current_text_value: Some(self.accessible_level_text(level)),
+            ..WidgetInfo::slider(ui.is_enabled(), level, "")
+        });
+        #[cfg(feature = "accesskit")]
+        self.announcement_ui(ui, response);
+        #[cfg(feature = "accesskit")]
+        ui.ctx().accesskit_node_builder(response.id, |builder| {
+            use egui::accesskit::Action;
+            let min = self.denormalise(0.0) as f64;
+            let max = self.denormalise(1.0) as f64;
+            // Screen readers expect a finite range.
+            builder.set_min_numeric_value(min.max(self.increments[0] as f64));
+            builder.set_max_numeric_value(max);
+            builder.set_numeric_value(level.max(self.increments[0] as f64));
+            builder.set_numeric_value_step(self.key_step as f64);
+            builder.add_action(Action::SetValue);
+            if level < max {
+                builder.add_action(Action::Increment);
+            }
+            if level > min {
+                builder.add_action(Action::Decrement);
+            }
+        });
+    }
+
+    fn accessible_level_text(&self, level: f64) -> String {
+        match &self.accessibility_text {
+            Some(text) => text(level),
+            None => self.level_text(level),
+        }
+    }
+
+    /// A polite live region announcing when the signal starts clipping and its peak.
+    #[cfg(feature = "accesskit")]
+    fn announcement_ui(&self, ui: &Ui, response: &Response) {
+        use egui::accesskit::{Live, Role};
+        let announcement_id = response.id.with("announcement");
+        let Some(announcement) = self.announcement.clone() else {
+            return;
+        };
+        ui.ctx().accesskit_node_builder(announcement_id, |builder| {
+            builder.set_role(Role::Status);
+            builder.set_live(Live::Polite);
+            builder.set_value(announcement);
+        });
+    }
+
+    /// Ratio to scale adjustments by depending on the held modifiers.
+    fn adjustment_ratio(&self, ui: &Ui) -> f32 {
+        self.modifier_ratio(ui.input(|input| input.modifiers))
+    }
+
+    /// Like [`Self::adjustment_ratio`], ignoring the modifiers held for a group drag.
+    fn drag_adjustment_ratio(&self, ui: &Ui) -> f32 {
+        let modifiers = ui.input(|input| input.modifiers);
+        if self.group_drag(modifiers) {
+            self.modifier_ratio(without_modifiers(modifiers, self.group_drag_modifiers))
+        } else {
+            self.modifier_ratio(modifiers)
+        }
+    }
+
+    fn modifier_ratio(&self, modifiers: Modifiers) -> f32 {
+        if any_modifier_held(modifiers, self.coarse_drag_modifiers) {
+            self.coarse_drag_ratio
+        } else if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        }
+    }
+
+    /// Shrink rect to allow for text underneath.
+    fn content_rect(&self, rect: Rect) -> Rect {
+        if !self.show_text {
+            return rect;
+        }
+        let bottom_padding = self.text_size + self.text_padding();
+        rect.shrink2(vec2(0.0, bottom_padding))
+            .translate(vec2(0.0, -bottom_padding * 0.5))
+    }
+
+    /// The row underneath the fader holding the level readout.
+    fn readout_rect(&self, rect: Rect) -> Rect {
+        let top = self.content_rect(rect).bottom() + self.text_padding();
+        Rect::from_x_y_ranges(rect.x_range(), top..=top + self.text_size)
+    }
+
+    /// The areas of a fader shown in `rect`.
+    fn rects(&self, rect: Rect) -> FaderRects {
+        let content = self.content_rect(rect);
+        // Divide the content into three sections.
+        let (rail, meter) = content.split_left_right_at_fraction(self.rail_fraction());
+        let (labels, meter) = if self.labels_shown() {
+            meter.split_left_right_at_fraction(0.5)
+        } else {
+            (Rect::NOTHING, meter)
+        };
+        FaderRects {
+            rail,
+            labels,
+            meter,
+            readout: self.readout_rect(rect),
+        }
+    }
+
+    fn fader_ui(&mut self, ui: &Ui, response: &Response) {
+        let rects = self.rects(response.rect);
+        let (left, middle, right) = (rects.rail, rects.labels, rects.meter);
+        let rail_response = response.clone().with_new_rect(left);
+        let surface = if self.drag_anywhere {
+            response.rect
+        } else {
+            left
+        };
+        let surface = surface.expand2(self.touch_margin(response.rect));
+        self.fader_interaction(ui, &rail_response, surface);
+        self.rail_ui(ui, &rail_response);
+        if rail_response.has_focus() {
+            self.focus_ring_ui(ui, left);
+        }
+        if self.labels_shown() {
+            self.label_ui(ui, middle, &rail_response);
+        }
+        let clip_cleared = self.clip_indicator
+            && ui
+                .interact(
+                    self.clip_rect(right),
+                    clip_id(rail_response.id),
+                    Sense::click(),
+                )
+                .clicked();
+        self.update_meter(ui, &rail_response, right.height(), clip_cleared);
+        self.signal_ui(ui, right, rail_response.id);
+        if self.clip_indicator {
+            self.clip_ui(ui, right);
+        }
+        if self.muted {
+            self.muted_ui(ui, right);
+        }
+        if self.overlay.is_some() {
+            self.overlay_ui(ui, &rail_response, &rects);
+        }
+    }
+
+    fn overlay_ui(&mut self, ui: &Ui, rail_response: &Response, rects: &FaderRects) {
+        let level = self.get_level();
+        let handle_shape = self.handle_shape(ui);
+        let Some(overlay) = &self.overlay else {
+            return;
+        };
+        let state = FaderState {
+            level,
+            dragged: rail_response.dragged(),
+            position_range: self.position_range(&rail_response.rect, &handle_shape),
+            increments: &self.increments,
+            weights: self.weights(),
+        };
+        overlay(ui.painter(), rects, &state);
+    }
+
+    /// Whether the signal of any channel is above the overload level.
+    fn clipped(&self) -> bool {
+        self.signal
+            .channels()
+            .iter()
+            .any(|signal| *signal > self.overload_level)
+    }
+
+    /// Ring around the rail while the fader has keyboard focus.
+    fn focus_ring_ui(&self, ui: &Ui, rect: Rect) {
+        let visuals = ui.visuals();
+        ui.painter().rect_stroke(
+            rect,
+            visuals.widgets.hovered.corner_radius,
+            visuals.selection.stroke,
+            epaint::StrokeKind::Outside,
+        );
+    }
+
+    /// Where the clip light is drawn across the top of the meter.
+    fn clip_rect(&self, meter: Rect) -> Rect {
+        Rect::from_min_size(meter.min, vec2(meter.width(), 0.5 * self.text_size))
+    }
+
+    /// Light across the top of the meter that latches when the signal clips.
+    fn clip_ui(&self, ui: &Ui, rect: Rect) {
+        let light_rect = self.clip_rect(rect);
+        let (corner, channel_colour) = self.channel_style(ui);
+        let colour = if self.clip_lit {
+            self.style.clip.unwrap_or(ui.visuals().error_fg_color)
+        } else {
+            channel_colour
+        };
+        ui.painter().rect_filled(light_rect, corner, colour);
+    }
+
+    /// Badge at the top of the meter showing the fader is muted.
+    fn muted_ui(&self, ui: &Ui, rect: Rect) {
+        let visuals = ui.visuals();
+        let size = Vec2::splat(self.text_size + 2.0 * self.text_padding());
+        let badge_rect = Rect::from_center_size(pos2(rect.center().x, rect.top()), size);
+        ui.painter().rect_filled(
+            badge_rect,
+            visuals.widgets.inactive.corner_radius,
+            self.style.mute.unwrap_or(visuals.warn_fg_color),
+        );
+        ui.painter().text(
+            badge_rect.center(),
+            Align2::CENTER_CENTER,
+            "M",
+            FontId::proportional(self.text_size),
+            visuals.extreme_bg_color,
+        );
+    }
+
+    /// Dim meter colours while muted.
+    fn meter_colour(&self, colour: Color32) -> Color32 {
+        if self.muted {
+            colour.gamma_multiply(0.3)
+        } else {
+            colour
+        }
+    }
+
+    fn rail_ui(&mut self, ui: &Ui, response: &Response) {
+        // Rail for fader handle.
+        let visuals = ui.style().interact(response);
+        let rect = response.rect;
+        let rail_radius = self
+            .rail_width
+            .unwrap_or_else(|| ui.spacing().slider_rail_height)
+            * 0.5;
+        let rail_rect = Rect::from_min_max(
+            pos2(rect.center().x - rail_radius, rect.top()),
+            pos2(rect.center().x + rail_radius, rect.bottom()),
+        );
+        let rail_corner = self
+            .style
+            .rail_corner_radius
+            .unwrap_or(ui.visuals().widgets.inactive.corner_radius);
+        let rail_style = self
+            .style
+            .rail
+            .unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        self.fill(ui, rail_rect, rail_corner, rail_style);
+
+        // Fader handle.
+        let handle_radius = self.handle_radius(&rect);
+        let handle_shape = self.handle_shape(ui);
+        let level = self.get_level();
+        let position_range = self.position_range(&rect, &handle_shape);
+        let normalised = self.smoothed(ui, response, self.normalise(level as f32));
+        let center = pos2(rect.center().x, lerp(position_range, normalised));
+
+        // Reference lines across the rail.
+        let line_stroke = self
+            .style
+            .reference_line
+            .unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke);
+        let line_x = Rangef::new(
+            rect.center().x - handle_radius,
+            rect.center().x + handle_radius,
+        );
+        let neutral_line = self.neutral_line.then_some(self.neutral_level);
+        for &value in self.reference_lines.iter().chain(&neutral_line) {
+            let line_y = self.position_from_value(value, position_range);
+            ui.painter().hline(line_x, line_y, line_stroke);
+        }
+
+        if self.ticks {
+            self.ticks_ui(ui, &rect, position_range);
+        }
+
+        // Fill from the neutral level to the handle.
+        if self.bipolar {
+            let neutral_y = self.position_from_value(self.neutral_level, position_range);
+            let fill_rect = Rect::from_x_y_ranges(
+                rail_rect.x_range(),
+                Rangef::new(neutral_y, center.y).as_positive(),
+            );
+            let fill_colour = self
+                .style
+                .rail_fill
+                .or(self.accent_color)
+                .unwrap_or(ui.visuals().selection.bg_fill);
+            self.fill(ui, fill_rect, rail_corner, fill_colour);
+        }
+
+        if let Some(offset) = self.vca_offset {
+            let effective = group::offset_level(level as f32, offset);
+            let ghost_y = self.position_from_value(effective, position_range);
+            let ghost_colour = self
+                .style
+                .handle_fill
+                .unwrap_or(visuals.bg_fill)
+                .gamma_multiply(0.4);
+            self.ghost_ui(
+                ui,
+                pos2(center.x, ghost_y),
+                handle_radius,
+                handle_shape,
+                ghost_colour,
+            );
+        }
+
+        let handle_fill = self
+            .style
+            .handle_fill
+            .or(self
+                .accent_color
+                .map(|accent| visuals.bg_fill.lerp_to_gamma(accent, 0.7)))
+            .unwrap_or(visuals.bg_fill);
+        let handle_fill = if self.muted {
+            ui.visuals().widgets.noninteractive.bg_fill
+        } else {
+            handle_fill
+        };
+        let handle_stroke = self.style.handle_stroke.unwrap_or(visuals.fg_stroke);
+        match (self.handle_image, handle_shape) {
+            (Some(texture_id), _) => {
+                let v = match handle_shape {
+                    HandleShape::Circle => Vec2::splat(handle_radius),
+                    HandleShape::Rect { aspect_ratio } => {
+                        Vec2::new(handle_radius, handle_radius * aspect_ratio)
+                    }
+                };
+                let v = v + Vec2::splat(visuals.expansion);
+                let rect = Rect::from_center_size(center, 2.0 * v);
+                let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+                ui.painter().image(texture_id, rect, uv, Color32::WHITE);
+            }
+            (None, _) if self.skeuomorphic_handle => {
+                let aspect_ratio = match handle_shape {
+                    HandleShape::Circle => 0.5,
+                    HandleShape::Rect { aspect_ratio } => aspect_ratio,
+                };
+                let v = Vec2::new(handle_radius, handle_radius * aspect_ratio);
+                let v = v + Vec2::splat(visuals.expansion);
+                let rect = Rect::from_center_size(center, 2.0 * v);
+                skeuomorphic_handle_ui(ui, rect, visuals.corner_radius, handle_fill, handle_stroke);
+            }
+            (None, HandleShape::Circle) => {
+                ui.painter().add(epaint::CircleShape {
+                    center,
+                    radius: handle_radius + visuals.expansion,
+                    fill: handle_fill,
+                    stroke: handle_stroke,
+                });
+            }
+            (None, HandleShape::Rect { aspect_ratio }) => {
+                let v = Vec2::new(handle_radius, handle_radius * aspect_ratio);
+                let v = v + Vec2::splat(visuals.expansion);
+                let rect = Rect::from_center_size(center, 2.0 * v);
+                ui.painter().rect(
+                    rect,
+                    visuals.corner_radius,
+                    handle_fill,
+                    handle_stroke,
+                    epaint::StrokeKind::Inside,
+                );
+            }
+        }
+
+        // Level text
+        if !self.show_text || self.is_editing(ui, response.id) {
+            return;
+        }
+        let level_text = self.level_text(level);
+        let text_pos = rect.center_bottom() + vec2(0.0, self.text_padding());
+        let text_anchor = Align2::CENTER_TOP;
+        let font_id = self.font(&self.readout_font);
+        let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+        ui.painter()
+            .text(text_pos, text_anchor, level_text, font_id, text_colour);
+    }
+
+    /// Translucent handle at the effective level of a VCA group member.
+    fn ghost_ui(
+        &self,
+        ui: &Ui,
+        center: Pos2,
+        handle_radius: f32,
+        handle_shape: HandleShape,
+        colour: Color32,
+    ) {
+        let stroke = Stroke::new(1.0, colour.gamma_multiply(1.5));
+        match handle_shape {
+            HandleShape::Circle => {
+                ui.painter().add(epaint::CircleShape {
+                    center,
+                    radius: handle_radius,
+                    fill: colour,
+                    stroke,
+                });
+            }
+            HandleShape::Rect { aspect_ratio } => {
+                let rect = Rect::from_center_size(
+                    center,
+                    2.0 * vec2(handle_radius, handle_radius * aspect_ratio),
+                );
+                let corner = ui.visuals().widgets.inactive.corner_radius;
+                ui.painter()
+                    .rect(rect, corner, colour, stroke, epaint::StrokeKind::Inside);
+            }
+        }
+    }
+
+    /// Tick marks along the right edge of the rail column.
+    fn ticks_ui(&self, ui: &Ui, rect: &Rect, position_range: Rangef) {
+        let [major_length, minor_length] = self.tick_lengths;
+        let major_stroke = self
+            .style
+            .tick
+            .unwrap_or(ui.visuals().widgets.noninteractive.fg_stroke);
+        let minor_stroke = Stroke::new(
+            major_stroke.width * 0.5,
+            major_stroke.color.gamma_multiply(0.5),
+        );
+        let right = rect.right();
+        for index in 0..self.increments.len() {
+            let y = lerp(position_range, self.breakpoint(index));
+            ui.painter()
+                .hline(right - major_length..=right, y, major_stroke);
+        }
+        for index in 1..self.increments.len() {
+            let start = self.breakpoint(index - 1);
+            let end = self.breakpoint(index);
+            for minor in 1..=self.minor_ticks {
+                let t = minor as f32 / (self.minor_ticks + 1) as f32;
+                let y = lerp(position_range, lerp(start..=end, t));
+                ui.painter()
+                    .hline(right - minor_length..=right, y, minor_stroke);
+            }
+        }
+    }
+
+    /// Glide the handle towards levels changed from outside, except while dragging.
+    fn smoothed(&self, ui: &Ui, response: &Response, normalised: f32) -> f32 {
+        let Some(smoothing) = self.smoothing else {
+            return normalised;
+        };
+        let id = response.id.with("smooth");
+        if response.dragged() {
+            ui.ctx().animate_value_with_time(id, normalised, 0.0);
+            normalised
+        } else {
+            ui.ctx().animate_value_with_time(id, normalised, smoothing)
+        }
+    }
+
+    fn edit_id(&self, id: Id) -> Id {
+        id.with("edit")
+    }
+
+    fn is_editing(&self, ui: &Ui, id: Id) -> bool {
+        let edit_id = self.edit_id(id);
+        ui.data(|data| data.get_temp::<String>(edit_id).is_some())
+    }
+
+    /// Parse typed text into a level. Values outside the range are clamped.
+    fn parse_level(&self, text: &str) -> Option<f64> {
+        let value = match &self.custom_parser {
+            Some(parser) => parser(text)? as f32,
+            None => self.default_parse_level(text)?,
+        };
+        Some(self.clamp_level(value))
+    }
+
+    /// Keep a level within the fader, anything below the bottom increment goes to the bottom.
+    fn clamp_level(&self, value: f32) -> f64 {
+        let min = self.increments[0];
+        let max = self.increments[self.increments.len() - 1];
+        let value = if value < min {
+            self.denormalise(0.0)
+        } else {
+            value.min(max)
+        };
+        value as f64
+    }
+
+    fn default_parse_level(&self, text: &str) -> Option<f32> {
+        let text = text.trim();
+        let text = text.strip_suffix(self.suffix.trim()).unwrap_or(text);
+        let text = text.trim_end().trim_end_matches('%').trim_end();
+        let value = match text {
+            "-inf" | "-∞" | "−∞" => -INFINITY,
+            _ if text == self.infinity_text.trim() => -INFINITY,
+            _ => text.parse::<f32>().ok()?,
+        };
+        match self.display_mode {
+            DisplayMode::Level => Some(value),
+            DisplayMode::Percent => Some(self.denormalise(value / 100.0)),
+        }
+    }
+
+    fn context_menu_ui(&mut self, response: &Response) {
+        if !self.context_menu {
+            return;
+        }
+        let add_items = self.context_menu_items.take();
+        response.context_menu(|ui| {
+            if ui.button("Reset").clicked() {
+                self.set_to_neutral();
+                ui.close_menu();
+            }
+            if self.neg_infinity_at_bottom
+                && ui
+                    .button(format!("Set to {}", self.infinity_text))
+                    .clicked()
+            {
+                self.set_level(-INFINITY as f64);
+                ui.close_menu();
+            }
+            if self.show_text && ui.button("Enter value…").clicked() {
+                self.start_editing(ui, response.id);
+                ui.close_menu();
+            }
+            if let Some(add_items) = add_items {
+                ui.separator();
+                add_items(ui);
+            }
+        });
+    }
+
+    fn tooltip_text(&self, level: f64) -> String {
+        let mut text = match self.display_mode {
+            DisplayMode::Level if level == f64::NEG_INFINITY => self.infinity_text.clone(),
+            DisplayMode::Level => format!("{level:.3}"),
+            DisplayMode::Percent => format!("{:.2}%", self.percent_from_value(level as f32)),
+        };
+        if let Some(offset) = self.vca_offset {
+            let effective = group::offset_level(level as f32, offset) as f64;
+            text += &format!("\nEffective: {}", self.level_text(effective));
+        }
+        if self.tooltip_signal {
+            match (self.signal, self.peak) {
+                (SignalKind::Mono(signal), Some(SignalKind::Mono(peak))) => {
+                    text += &format!("\nSignal: {signal:.2}\nPeak: {peak:.2}");
+                }
+                (SignalKind::Stereo([left, right]), Some(SignalKind::Stereo([lpeak, rpeak]))) => {
+                    text += &format!("\nSignal: {left:.2} / {right:.2}");
+                    text += &format!("\nPeak: {lpeak:.2} / {rpeak:.2}");
+                }
+                _ => {}
+            }
+        }
+        text
+    }
+
+    fn tooltip_ui(&mut self, ui: &Ui, response: &Response) {
+        if !self.tooltip || !(response.hovered() || response.dragged()) {
+            return;
+        }
+        let level = self.get_level();
+        let text = self.tooltip_text(level);
+        let id = response.id.with("tooltip");
+        egui::show_tooltip_at_pointer(ui.ctx(), ui.layer_id(), id, |ui| ui.label(text));
+    }
+
+    fn start_editing(&mut self, ui: &Ui, id: Id) {
+        if !self.show_text || self.is_editing(ui, id) {
+            return;
+        }
+        let edit_id = self.edit_id(id);
+        let level = self.get_level();
+        let text = self.level_text(level);
+        ui.data_mut(|data| data.insert_temp(edit_id, text));
+        ui.memory_mut(|mem| mem.request_focus(edit_id));
+    }
+
+    /// Clicking the readout swaps it for a text edit to type an exact level.
+    /// Enter commits the value, escape or clicking elsewhere cancels.
+    fn value_entry_ui(&mut self, ui: &mut Ui, response: &Response) {
+        if !self.show_text {
+            return;
+        }
+        let rect = self.readout_rect(response.rect);
+        let edit_id = self.edit_id(response.id);
+        let readout_response = ui.interact(rect, response.id.with("readout"), Sense::click());
+        if readout_response.clicked() {
+            self.start_editing(ui, response.id);
+        }
+        let Some(mut text) = ui.data(|data| data.get_temp::<String>(edit_id)) else {
+            return;
+        };
+        let edit_response = ui.put(
+            rect,
+            TextEdit::singleline(&mut text)
+                .id(edit_id)
+                .font(self.font(&self.readout_font))
+                .horizontal_align(Align::Center)
+                .margin(Margin::ZERO),
+        );
+        if edit_response.lost_focus() {
+            let (enter, escape) = ui.input(|input| {
+                (
+                    input.key_pressed(Key::Enter),
+                    input.key_pressed(Key::Escape),
+                )
+            });
+            if enter && let Some(level) = self.parse_level(&text) {
+                self.set_level(level);
+            }
+            // Hand focus back to the fader when editing from the keyboard.
+            if enter || escape {
+                ui.memory_mut(|mem| mem.request_focus(response.id));
+            }
+            ui.data_mut(|data| data.remove::<String>(edit_id));
+        } else {
+            ui.data_mut(|data| data.insert_temp(edit_id, text));
+        }
+    }
+
+    fn label_ui(&self, ui: &Ui, rect: Rect, rail_response: &Response) {
+        let rail_rect = &rail_response.rect;
+        let handle_shape = self.handle_shape(ui);
+        let text_anchor = Align2::CENTER_CENTER;
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        let position_range = self.position_range(rail_rect, &handle_shape);
+        let label_font = self.font(&self.label_font);
+        let labels = || {
+            self.increments
+                .iter()
+                .enumerate()
+                .filter_map(|(index, &value)| {
+                    let text = self.label_text(value, index);
+                    if text.is_empty() {
+                        return None;
+                    }
+                    let mut font_id = label_font.clone();
+                    if self.is_infinity_label(index) && text.contains('∞') {
+                        // Account for the small infinity symbol.
+                        font_id.size *= 1.5;
+                    }
+                    let text = if self.suffix_on_labels {
+                        text + &self.suffix
+                    } else {
+                        text
+                    };
+                    Some((index, text, font_id))
+                })
+        };
+        let galleys = cached_galleys(ui, rail_response.id.with("labels"), labels, text_colour);
+        for (index, galley) in galleys.iter() {
+            let text_y = lerp(position_range, self.breakpoint(*index));
+            let text_rect = text_anchor.anchor_size(pos2(rect.center().x, text_y), galley.size());
+            ui.painter()
+                .galley(text_rect.min, Arc::clone(galley), text_colour);
+        }
+    }
+
+    fn is_infinity_label(&self, index: usize) -> bool {
+        index == 0
+            && self.neg_infinity_at_bottom
+            && self.infinity_label
+            && self.label_formatter.is_none()
+            && self.display_mode != DisplayMode::Percent
+    }
+
+    fn label_text(&self, value: f32, index: usize) -> String {
+        if let Some(formatter) = &self.label_formatter {
+            formatter(value, index)
+        } else if self.display_mode == DisplayMode::Percent {
+            format!("{:.0}%", self.percent_from_value(value))
+        } else if self.is_infinity_label(index) {
+            self.infinity_text.clone()
+        } else {
+            format!("{value}")
+        }
+    }
+
+    fn channel_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(ui.style().visuals.widgets.inactive.corner_radius);
+        let colour = self.style.channel.unwrap_or(ui.visuals().faint_bg_color);
+        (corner, colour)
+    }
+
+    fn signal_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(ui.style().visuals.widgets.inactive.corner_radius);
+        let colour = self
+            .style
+            .signal
+            .or(self.accent_color)
+            .unwrap_or(ui.visuals().widgets.active.fg_stroke.color);
+        (corner, self.meter_colour(colour))
+    }
+
+    fn peak_style(&self, ui: &Ui) -> (CornerRadius, Color32) {
+        let corner = self
+            .style
+            .peak_corner_radius
+            .unwrap_or(ui.style().visuals.widgets.active.corner_radius);
+        let colour = self
+            .style
+            .peak
+            .unwrap_or(ui.visuals().widgets.inactive.fg_stroke.color);
+        (corner, self.meter_colour(colour))
+    }
+
+    fn channel_radius(&self, ui: &Ui) -> f32 {
+        self.meter_width
+            .unwrap_or_else(|| ui.spacing().slider_rail_height)
+            * 0.5
     }
 
     fn channel_ui(&self, ui: &Ui, rect: &Rect, signal: f32, peak: f32, centre: f32) {
@@ -364,8 +2952,8 @@ impl<'a> Fader<'a> {
         let (signal_corner, signal_colour) = self.signal_style(ui);
         let (peak_corner, peak_colour) = self.peak_style(ui);
         let channel_radius = self.channel_radius(ui);
-        let signal = normalised_from_value(signal, self.increments.clone());
-        let peak = normalised_from_value(peak, self.increments.clone());
+        let signal = self.normalise(signal);
+        let peak = self.normalise(peak);
         let peak_height = rect.size().y * peak;
         let signal_height = rect.size().y * signal;
         let signal_y = rect.bottom() - signal_height;
@@ -379,80 +2967,340 @@ impl<'a> Fader<'a> {
             pos2(centre - channel_radius, signal_y),
             vec2(2.0 * channel_radius, signal_height),
         );
-        let peak_rect =
-            Rect::from_center_size(pos2(centre, peak_y), Vec2::splat(2.0 * channel_radius));
-        ui.painter()
-            .rect_filled(channel_rect, channel_corner, channel_colour);
+        self.fill(ui, channel_rect, channel_corner, channel_colour);
+        if self.overload_shading {
+            let overload_y = rect.bottom() - rect.height() * self.normalise(self.overload_level);
+            let overload_rect = channel_rect.intersect(Rect::from_x_y_ranges(
+                channel_rect.x_range(),
+                rect.top()..=overload_y,
+            ));
+            let overload_colour = self
+                .style
+                .overload
+                .unwrap_or(ui.visuals().error_fg_color.gamma_multiply(0.2));
+            if overload_rect.is_positive() {
+                self.fill(ui, overload_rect, channel_corner, overload_colour);
+            }
+        }
+        match &self.style.signal_gradient {
+            None => self.fill(ui, signal_rect, signal_corner, signal_colour),
+            Some(gradient) => {
+                for (start, end, colour) in gradient.zones(|level| self.normalise(level)) {
+                    let zone_y = Rangef::new(
+                        rect.bottom() - rect.height() * end,
+                        rect.bottom() - rect.height() * start,
+                    );
+                    let zone_rect =
+                        signal_rect.intersect(Rect::from_x_y_ranges(signal_rect.x_range(), zone_y));
+                    if zone_rect.is_positive() {
+                        let colour = self.meter_colour(colour);
+                        self.fill(ui, zone_rect, signal_corner, colour);
+                    }
+                }
+            }
+        }
+        match self.peak_marker {
+            PeakMarker::Square => {
+                let peak_rect =
+                    Rect::from_center_size(pos2(centre, peak_y), Vec2::splat(2.0 * channel_radius));
+                self.fill(ui, peak_rect, peak_corner, peak_colour);
+            }
+            PeakMarker::Line => {
+                let peak_y = peak_y.clamp(
+                    rect.top() + 0.5 * self.peak_thickness,
+                    rect.bottom() - 0.5 * self.peak_thickness,
+                );
+                let peak_rect = Rect::from_center_size(
+                    pos2(centre, peak_y),
+                    vec2(2.0 * channel_radius, self.peak_thickness),
+                );
+                self.fill(ui, peak_rect, CornerRadius::ZERO, peak_colour);
+            }
+            PeakMarker::Triangle => {
+                let tip = pos2(centre - channel_radius, peak_y);
+                let points = [
+                    tip,
+                    tip + vec2(-2.0 * channel_radius, -channel_radius),
+                    tip + vec2(-2.0 * channel_radius, channel_radius),
+                ];
+                self.fill_triangle(ui, points, peak_colour);
+            }
+        }
+    }
+
+    /// Replace NaN and `+∞` signals following [`Self::invalid_signal`].
+    fn resolve_signal(&mut self) {
+        let top = self.increments[self.increments.len() - 1];
+        let policy = self.invalid_signal;
+        let (signal, invalid) = match self.signal {
+            SignalKind::Mono(signal) => {
+                let (signal, invalid) = policy.resolve(signal, top);
+                (SignalKind::Mono(signal), [invalid, false])
+            }
+            SignalKind::Stereo([left, right]) => {
+                let (left, left_invalid) = policy.resolve(left, top);
+                let (right, right_invalid) = policy.resolve(right, top);
+                (
+                    SignalKind::Stereo([left, right]),
+                    [left_invalid, right_invalid],
+                )
+            }
+        };
+        self.signal = signal;
+        self.invalid_channels = invalid;
+    }
+
+    /// Cross over a meter channel showing an invalid signal.
+    fn invalid_ui(&self, ui: &Ui, rect: &Rect, centre: f32) {
+        let channel_radius = self.channel_radius(ui);
+        let channel_rect = Rect::from_x_y_ranges(
+            centre - channel_radius..=centre + channel_radius,
+            rect.y_range(),
+        );
+        let cross = Rect::from_center_size(
+            channel_rect.center(),
+            Vec2::splat(4.0 * channel_radius).min(channel_rect.size()),
+        );
+        let stroke = Stroke::new(1.5, ui.visuals().error_fg_color);
         ui.painter()
-            .rect_filled(signal_rect, signal_corner, signal_colour);
+            .line_segment([cross.left_top(), cross.right_bottom()], stroke);
         ui.painter()
-            .rect_filled(peak_rect, peak_corner, peak_colour);
+            .line_segment([cross.right_top(), cross.left_bottom()], stroke);
     }
 
-    fn signal_ui(&self, ui: &Ui, rect: Rect, rail_response: &Response) {
+    fn signal_ui(&self, ui: &Ui, rect: Rect, id: Id) {
+        let peak = self.peak.unwrap_or(self.signal);
+        let show_invalid = self.invalid_signal == InvalidSignal::Error;
         match self.signal {
             SignalKind::Mono(signal) => {
-                let SignalKind::Mono(peak) = self.next_peak(ui, rail_response, self.signal) else {
+                let SignalKind::Mono(peak) = peak else {
                     panic!()
                 };
                 let centre = rect.center().x;
                 self.channel_ui(ui, &rect, signal, peak, centre);
+                if show_invalid && self.invalid_channels[0] {
+                    self.invalid_ui(ui, &rect, centre);
+                }
             }
             SignalKind::Stereo([left, right]) => {
-                let SignalKind::Stereo([left_peak, right_peak]) =
-                    self.next_peak(ui, rail_response, self.signal)
-                else {
+                let SignalKind::Stereo([left_peak, right_peak]) = peak else {
                     panic!()
                 };
                 let left_x = rect.left() + rect.size().x * 1.0 / 3.0;
                 let right_x = rect.left() + rect.size().x * 2.0 / 3.0;
                 self.channel_ui(ui, &rect, left, left_peak, left_x);
                 self.channel_ui(ui, &rect, right, right_peak, right_x);
+                for (invalid, x) in self.invalid_channels.into_iter().zip([left_x, right_x]) {
+                    if show_invalid && invalid {
+                        self.invalid_ui(ui, &rect, x);
+                    }
+                }
 
-                // Text to label the left and right channels.
-                let left_pos = pos2(left_x, rect.bottom() + self.text_padding());
-                let right_pos = pos2(right_x, rect.bottom() + self.text_padding());
-                let text_anchor = Align2::CENTER_TOP;
-                let font_id = FontId::proportional(self.text_size);
-                let text_colour = ui.style().visuals.text_color();
-                ui.painter()
-                    .text(left_pos, text_anchor, "L", font_id.clone(), text_colour);
-                ui.painter()
-                    .text(right_pos, text_anchor, "R", font_id.clone(), text_colour);
+                // Text to label the channels.
+                if !self.show_text {
+                    return;
+                }
+                let y = rect.bottom() + self.text_padding();
+                let font_id = self.font(&self.channel_label_font);
+                let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+                let labels = || {
+                    (self.channel_labels.iter().enumerate())
+                        .filter(|(_, label)| !label.is_empty())
+                        .map(|(index, label)| (index, label.to_string(), font_id.clone()))
+                };
+                let galleys = cached_galleys(ui, id.with("channel_labels"), labels, text_colour);
+                for (index, galley) in galleys.iter() {
+                    let pos = pos2([left_x, right_x][*index], y);
+                    let text_rect = Align2::CENTER_TOP.anchor_size(pos, galley.size());
+                    ui.painter()
+                        .galley(text_rect.min, Arc::clone(galley), text_colour);
+                }
             }
         }
     }
 
-    /// Get the peak from the recent buffer.
-    fn next_peak(&self, ui: &Ui, response: &Response, signal: SignalKind) -> SignalKind {
-        let id = response.id.with("peak");
-        ui.memory_mut(|mem| {
-            let queue = mem
-                .data
-                .get_temp_mut_or_insert_with::<FaderPeak>(id, || match signal {
-                    SignalKind::Mono(..) => {
-                        FaderPeak::Mono(PeakDetector::new(self.peak_buffer_size))
-                    }
-                    SignalKind::Stereo(..) => FaderPeak::Stereo([
-                        PeakDetector::new(self.peak_buffer_size),
-                        PeakDetector::new(self.peak_buffer_size),
-                    ]),
-                });
-            queue.next(signal)
-        })
+    /// Move the meter on a frame: the held peaks, the clip light and whether to repaint. The
+    /// state is the caller's [`MeterState`] if there is one, otherwise it is in memory.
+    fn update_meter(&mut self, ui: &Ui, rail_response: &Response, height: f32, clip_cleared: bool) {
+        let mut meter_state = self.meter_state.take();
+        let update = |state: &mut MeterState| self.next_meter(state, height, clip_cleared);
+        let frame = match meter_state.as_deref_mut() {
+            Some(state) => update(state),
+            None => {
+                ui.data_mut(|data| update(data.get_temp_mut_or_default(peak_id(rail_response.id))))
+            }
+        };
+        self.meter_state = meter_state;
+        self.peak = Some(frame.peak);
+        self.clip_lit = frame.clip_lit;
+        #[cfg(feature = "accesskit")]
+        {
+            self.announcement = frame.announcement;
+        }
+        if frame.repaint {
+            match self.max_meter_refresh {
+                Some(hz) => ui.ctx().request_repaint_after_secs(hz.recip()),
+                None => ui.ctx().request_repaint(),
+            }
+        }
+    }
+
+    fn next_meter(&self, state: &mut MeterState, height: f32, clip_cleared: bool) -> MeterFrame {
+        let peak = state.next_peak(self.signal, self.peak_buffer_size);
+        let clipped = self.clipped();
+        #[cfg_attr(not(feature = "accesskit"), allow(unused_variables))]
+        let clip_started = state.update_clip(clipped, clip_cleared);
+        #[cfg(feature = "accesskit")]
+        if clip_started {
+            let peak = peak.channels().iter().copied().fold(-INFINITY, f32::max);
+            state.announcement = Some(format!("Clipped, peak {}", self.level_text(peak as f64)));
+        }
+        // Keep frames coming while the meter moves, see [`Self::meter_repaint`].
+        let repaint = self.meter_repaint.is_some_and(|epsilon| {
+            let shown = (self.signal.channels().iter())
+                .chain(peak.channels())
+                .map(|level| self.normalise(*level) * height);
+            meter_moved(&mut state.shown, shown, epsilon)
+        });
+        MeterFrame {
+            peak,
+            clip_lit: state.clip_lit(),
+            repaint,
+            #[cfg(feature = "accesskit")]
+            announcement: state.announcement.clone(),
+        }
     }
 
     fn add_contents(&mut self, ui: &mut Ui) -> Response {
         let old_level = self.get_level();
-        let width = 2.0
-            * ui.text_style_height(&TextStyle::Body)
-                .at_least(ui.spacing().interact_size.x);
+        let app_settings = if self.high_contrast.is_none() || self.reduced_motion.is_none() {
+            app_settings(ui)
+        } else {
+            AppSettings::default()
+        };
+        if self.high_contrast.unwrap_or(app_settings.high_contrast) {
+            let style = std::mem::take(&mut self.style);
+            self.style = style.or(FaderStyle::high_contrast(ui.visuals().dark_mode));
+            self.peak_thickness = self.peak_thickness.max(4.0);
+        }
+        if self.reduced_motion.unwrap_or(app_settings.reduced_motion) {
+            self.smoothing = None;
+            self.fling = false;
+            self.peak_buffer_size = 1;
+        }
+        self.resolve_signal();
+        if let Some(normalised) = self.external_normalised {
+            let level = self.denormalise(normalised);
+            self.set_level(level as f64);
+        }
+        if self.out_of_range == OutOfRange::ClampStored {
+            let level = old_level as f32;
+            let clamped = self.clamp_level(level);
+            if level != -INFINITY && clamped != level as f64 {
+                self.set_level(clamped);
+            }
+        }
+        let width = self.width.unwrap_or_else(|| {
+            2.0 * ui
+                .text_style_height(&TextStyle::Body)
+                .at_least(ui.spacing().interact_size.x)
+        });
+        // The rail takes a fifth of the full width, the labels two fifths and the meter the rest.
+        let width = if self.labels_only {
+            0.4 * width
+        } else if self.labels_shown() {
+            width
+        } else {
+            0.6 * width
+        };
         let height = self
             .height
             .unwrap_or_else(|| 1.5 * ui.spacing().slider_width);
         let size = vec2(width, height);
-        let mut response = ui.allocate_response(size, Sense::drag());
+        let margin = self
+            .frame
+            .map_or(epaint::Marginf::ZERO, |frame| frame.total_margin());
+        let outer_size = size + margin.sum();
+        // Interact with the expanded hit area but draw within the allocated rect.
+        let (id, outer_rect) = ui.allocate_space(outer_size);
+        let id = self
+            .id_salt
+            .map_or(id, |id_salt| ui.make_persistent_id(id_salt));
+        let rect = Rect::from_min_size(outer_rect.min + vec2(margin.left, margin.top), size);
+        if let Some(frame) = self.frame {
+            ui.painter().add(frame.paint(rect));
+        }
+        if self.labels_only {
+            let mut response = ui.interact(rect, id, Sense::hover());
+            response.intrinsic_size = Some(outer_size);
+            // Line the labels up with the rail that would be to the left of them.
+            let labels = self.content_rect(rect);
+            let rail = labels.translate(vec2(-0.5 * labels.width(), 0.0));
+            let rail = Rect::from_x_y_ranges(rail.left()..=labels.left(), rail.y_range());
+            self.label_ui(ui, labels, &response.clone().with_new_rect(rail));
+            return response;
+        }
+        let mut response = ui
+            // Always take part in Tab navigation so the fader works without a mouse.
+            .interact(
+                rect.expand2(self.touch_margin(rect)),
+                id,
+                self.sense | Sense::FOCUSABLE,
+            )
+            .with_new_rect(rect);
+        response.intrinsic_size = Some(outer_size);
+        let deferred = self.commit_on_release && (response.dragged() || response.drag_stopped());
+        // While deferring, the fader works on the in-flight level rather than the bound one.
+        // Both hold the level as stored, i.e. after any normalisation.
+        let bound = deferred.then(|| {
+            let pending_id = pending_id(response.id);
+            let level = if response.drag_started() {
+                None
+            } else {
+                ui.data(|data| data.get_temp(pending_id))
+            };
+            let pending = Rc::new(Cell::new(
+                level.unwrap_or_else(|| get(&mut self.get_set_value)),
+            ));
+            let in_flight = Rc::clone(&pending);
+            let get_set_value: GetSetValue<'a> = Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    in_flight.set(v);
+                }
+                in_flight.get()
+            });
+            (
+                std::mem::replace(&mut self.get_set_value, get_set_value),
+                pending,
+            )
+        });
+        let level_before = self.get_level() as f32;
+        let normalised_before = self.normalise(level_before);
         self.fader_ui(ui, &response);
+        self.selection_ui(ui, &response, normalised_before);
+        self.context_menu_ui(&response);
+        self.tooltip_ui(ui, &response);
+        self.value_entry_ui(ui, &response);
+        self.accessibility_ui(ui, &response);
+        if let Some((get_set_value, pending)) = bound {
+            self.get_set_value = get_set_value;
+            let pending_id = pending_id(response.id);
+            if response.drag_stopped() {
+                ui.data_mut(|data| data.remove::<f64>(pending_id));
+                // Already converted by `set_level` when it was stored.
+                set(&mut self.get_set_value, pending.get());
+            } else {
+                ui.data_mut(|data| data.insert_temp(pending_id, pending.get()));
+            }
+        }
+        // Only end gestures that started on the drag surface, see `fader_interaction`.
+        if response.drag_stopped()
+            && ui
+                .data_mut(|data| data.remove_temp::<bool>(gesture_id(response.id)))
+                .is_some()
+        {
+            self.gesture_end();
+        }
         if self.get_level() != old_level {
             response.mark_changed();
         }
@@ -460,9 +3308,164 @@ impl<'a> Fader<'a> {
     }
 }
 
+impl Fader<'_> {
+    /// Show the fader, like adding it with [`Ui::add`], and return its peaks, clipping and
+    /// drag gesture as well as the response.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_level: f32 = -10.0;
+    /// # let my_signal: f32 = 1.5;
+    /// # let mut my_undo_stack: Vec<f64> = Vec::new();
+    /// let output = egui_fader::Fader::mono(&mut my_level, my_signal).show(ui);
+    /// if output.clipped {
+    ///     // Latch a clip light.
+    /// }
+    /// if output.drag_stopped
+    ///     && let Some(level) = output.drag_start_level
+    /// {
+    ///     my_undo_stack.push(level);
+    /// }
+    /// # });
+    /// ```
+    pub fn show(mut self, ui: &mut Ui) -> FaderOutput {
+        let response = if self.interactive {
+            self.add_contents(ui)
+        } else {
+            ui.add_enabled_ui(false, |ui| self.add_contents(ui)).inner
+        };
+        let clipped = self.clipped();
+        FaderOutput {
+            rects: self.rects(response.rect),
+            peaks: self
+                .peak
+                .map_or_else(Vec::new, |peak| peak.channels().to_vec()),
+            clipped,
+            drag_started: response.drag_started(),
+            drag_stopped: response.drag_stopped(),
+            drag_start_level: (response.dragged() || response.drag_stopped())
+                .then(|| Self::drag_start_level(ui.ctx(), response.id))
+                .flatten(),
+            response,
+        }
+    }
+}
+
 impl Widget for Fader<'_> {
-    fn ui(mut self, ui: &mut Ui) -> Response {
-        self.add_contents(ui)
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+fn drag_start_id(id: Id) -> Id {
+    id.with("drag_start")
+}
+
+/// Present while a drag gesture started by the fader with this `id` is in progress.
+fn gesture_id(id: Id) -> Id {
+    id.with("gesture")
+}
+
+fn drag_owner_id(id: Id) -> Id {
+    id.with("drag_owner")
+}
+
+fn peak_id(id: Id) -> Id {
+    id.with("peak")
+}
+
+fn app_settings_id() -> Id {
+    Id::new("egui_fader_settings")
+}
+
+/// The settings for every fader in the app, e.g. from [`Fader::set_high_contrast`].
+fn app_settings(ui: &Ui) -> AppSettings {
+    ui.data(|data| data.get_temp(app_settings_id()).unwrap_or_default())
+}
+
+/// Whether reduced motion has been turned on with [`Fader::set_reduced_motion`].
+pub(crate) fn reduced_motion(ui: &Ui) -> bool {
+    app_settings(ui).reduced_motion
+}
+
+fn pending_id(id: Id) -> Id {
+    id.with("pending")
+}
+
+fn clip_id(id: Id) -> Id {
+    id.with("clip")
+}
+
+/// Paint a fader cap with a gradient fill, a groove across the centre and a drop shadow.
+fn skeuomorphic_handle_ui(
+    ui: &Ui,
+    rect: Rect,
+    corner_radius: CornerRadius,
+    fill: Color32,
+    stroke: Stroke,
+) {
+    let shadow = epaint::Shadow {
+        offset: [0, 2],
+        blur: 4,
+        spread: 0,
+        color: Color32::from_black_alpha(96),
+    };
+    ui.painter().add(shadow.as_shape(rect, corner_radius));
+
+    // Lit from above, darker towards the bottom.
+    let top = fill.lerp_to_gamma(Color32::WHITE, 0.3);
+    let bottom = fill.lerp_to_gamma(Color32::BLACK, 0.3);
+    let mut mesh = epaint::Mesh::default();
+    mesh.colored_vertex(rect.left_top(), top);
+    mesh.colored_vertex(rect.right_top(), top);
+    mesh.colored_vertex(rect.left_bottom(), bottom);
+    mesh.colored_vertex(rect.right_bottom(), bottom);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    ui.painter().add(mesh);
+
+    let groove = rect.shrink2(vec2(rect.width() * 0.1, 0.0)).x_range();
+    let centre = rect.center().y;
+    ui.painter().hline(
+        groove,
+        centre,
+        Stroke::new(1.0, fill.lerp_to_gamma(Color32::BLACK, 0.6)),
+    );
+    ui.painter().hline(
+        groove,
+        centre + 1.0,
+        Stroke::new(1.0, fill.lerp_to_gamma(Color32::WHITE, 0.5)),
+    );
+    ui.painter()
+        .rect_stroke(rect, corner_radius, stroke, epaint::StrokeKind::Inside);
+}
+
+/// A level as shown in a readout with this many decimals, or `infinity_text` for `NEG_INFINITY`.
+fn level_readout(level: f64, infinity_text: &str, decimals: usize) -> String {
+    if level == f64::NEG_INFINITY {
+        infinity_text.to_owned()
+    } else {
+        format!("{level:.decimals$}")
+    }
+}
+
+/// Whether any of the modifiers in `set` are held.
+fn any_modifier_held(held: Modifiers, set: Modifiers) -> bool {
+    (set.alt && held.alt)
+        || (set.ctrl && held.ctrl)
+        || (set.shift && held.shift)
+        || (set.mac_cmd && held.mac_cmd)
+        || (set.command && held.command)
+}
+
+/// The modifiers in `held` that aren't in `set`.
+fn without_modifiers(held: Modifiers, set: Modifiers) -> Modifiers {
+    Modifiers {
+        alt: held.alt && !set.alt,
+        ctrl: held.ctrl && !set.ctrl,
+        shift: held.shift && !set.shift,
+        mac_cmd: held.mac_cmd && !set.mac_cmd,
+        command: held.command && !set.command,
     }
 }
 
@@ -476,11 +3479,121 @@ impl Widget for Fader<'_> {
 
 // Normalised values of 0.0 will return `NEG_INFINITY`
 
-fn normalised_from_value(value: f32, increments: Vec<f32>) -> f32 {
-    if value == -INFINITY {
+// Segment weights are relative, `None` spaces the increments evenly.
+
+/// Normalised position of the increment at `index`.
+fn breakpoint(index: usize, increments: &[f32], weights: Option<&[f32]>) -> f32 {
+    match weights {
+        None => index as f32 / (increments.len() - 1) as f32,
+        Some(weights) => weights[..index].iter().sum::<f32>() / weights.iter().sum::<f32>(),
+    }
+}
+
+/// Normalised positions of all the increments, summing the weights once.
+fn breakpoints(increments: &[f32], weights: &[f32]) -> Vec<f32> {
+    let total = weights.iter().sum::<f32>();
+    let mut sum = 0.0;
+    let mut breakpoints = Vec::with_capacity(increments.len());
+    breakpoints.push(0.0);
+    for weight in weights {
+        sum += weight;
+        breakpoints.push(sum / total);
+    }
+    breakpoints
+}
+
+/// The galleys of `labels`, each an index, text and font, in `colour`. They are kept in memory
+/// under `id` and only laid out again when a label, a font, the colour or egui's font atlas
+/// changes, e.g. with the increments, text size or theme.
+fn cached_galleys<I>(
+    ui: &Ui,
+    id: Id,
+    labels: impl Fn() -> I,
+    colour: Color32,
+) -> Arc<[(usize, Arc<Galley>)]>
+where
+    I: Iterator<Item = (usize, String, FontId)>,
+{
+    let mut hasher = DefaultHasher::new();
+    for label in labels() {
+        label.hash(&mut hasher);
+    }
+    colour.hash(&mut hasher);
+    let key = hasher.finish();
+    let atlas = ui.fonts(|fonts| fonts.texture_atlas());
+    let cached = ui.data(|data| data.get_temp::<CachedGalleys>(id));
+    if let Some(cached) = cached
+        && cached.key == key
+        && cached.atlas.as_ptr() == Arc::as_ptr(&atlas)
+    {
+        return cached.galleys;
+    }
+    let galleys: Arc<[_]> = ui.fonts(|fonts| {
+        labels()
+            .map(|(index, text, font_id)| (index, fonts.layout_no_wrap(text, font_id, colour)))
+            .collect()
+    });
+    let cached = CachedGalleys {
+        atlas: Arc::downgrade(&atlas),
+        key,
+        galleys: Arc::clone(&galleys),
+    };
+    ui.data_mut(|data| data.insert_temp(id, cached));
+    galleys
+}
+
+/// Whether a meter has moved by more than `epsilon` since it was `last` shown, or a held peak is
+/// still above its signal. Both hold the signal positions of each channel then the peaks, and
+/// `last` is overwritten in place with the positions `shown` now.
+fn meter_moved(last: &mut Vec<f32>, shown: impl Iterator<Item = f32>, epsilon: f32) -> bool {
+    let mut moved = false;
+    let mut count = 0;
+    for position in shown {
+        match last.get_mut(count) {
+            Some(last) => {
+                moved |= (*last - position).abs() > epsilon;
+                *last = position;
+            }
+            None => {
+                moved = true;
+                last.push(position);
+            }
+        }
+        count += 1;
+    }
+    moved |= last.len() != count;
+    last.truncate(count);
+    let (signals, peaks) = last.split_at(count / 2);
+    moved
+        || signals
+            .iter()
+            .zip(peaks)
+            .any(|(signal, peak)| peak - signal > epsilon)
+}
+
+fn normalised_from_value(value: f32, increments: &[f32], weights: Option<&[f32]>) -> f32 {
+    normalised_with_breakpoints(value as f64, increments, |index| {
+        breakpoint(index, increments, weights)
+    }) as f32
+}
+
+fn value_from_normalised(normalised: f32, increments: &[f32], weights: Option<&[f32]>) -> f32 {
+    value_with_breakpoints(normalised as f64, increments, |index| {
+        breakpoint(index, increments, weights)
+    }) as f32
+}
+
+/// Mapped in `f64` so the fader can keep the precision of an `f64` level.
+fn normalised_with_breakpoints(
+    value: f64,
+    increments: &[f32],
+    breakpoint: impl Fn(usize) -> f32,
+) -> f64 {
+    if value == f64::NEG_INFINITY {
         return 0.0;
     }
-    let index = match increments.binary_search_by(|it| OrderedFloat(*it).cmp(&OrderedFloat(value)))
+    let index = match increments
+        .binary_search_by(|it| OrderedFloat(*it as f64).cmp(&OrderedFloat(value)))
     {
         Ok(index) => index,
         Err(index) => index,
@@ -490,44 +3603,76 @@ fn normalised_from_value(value: f32, increments: Vec<f32>) -> f32 {
     } else if index == 0 {
         0.0
     } else {
-        let left = increments[index - 1];
-        let right = increments[index];
-        remap(value, left..=right, (index - 1) as f32..=(index) as f32)
-            / (increments.len() - 1) as f32
+        let left = increments[index - 1] as f64;
+        let right = increments[index] as f64;
+        let start = breakpoint(index - 1) as f64;
+        let end = breakpoint(index) as f64;
+        lerp(start..=end, remap(value, left..=right, 0.0..=1.0))
     }
 }
 
-fn value_from_normalised(normalised: f32, increments: Vec<f32>) -> f32 {
+fn value_with_breakpoints(
+    normalised: f64,
+    increments: &[f32],
+    breakpoint: impl Fn(usize) -> f32,
+) -> f64 {
     if normalised >= 1.0 {
-        increments[increments.len() - 1]
+        increments[increments.len() - 1] as f64
     } else if normalised <= 0.0 {
-        -INFINITY
+        f64::NEG_INFINITY
     } else {
-        let float_index = normalised * (increments.len() - 1) as f32;
-        let index = float_index as usize;
-        let left = increments[index];
-        let right = increments[index + 1];
-        lerp(
-            left..=right,
-            remap(float_index, index as f32..=(index + 1) as f32, 0.0..=1.0),
-        )
+        let segments = increments.len() - 1;
+        let index = (0..segments)
+            .find(|index| normalised < breakpoint(index + 1) as f64)
+            .unwrap_or(segments - 1);
+        let start = breakpoint(index) as f64;
+        let end = breakpoint(index + 1) as f64;
+        let left = increments[index] as f64;
+        let right = increments[index + 1] as f64;
+        lerp(left..=right, remap(normalised, start..=end, 0.0..=1.0))
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod test {
+    use std::cell::RefCell;
+
     use super::*;
 
+    #[test]
+    fn invalid_signals_do_not_reach_the_peaks() {
+        for policy in [
+            InvalidSignal::Silence,
+            InvalidSignal::Clamp,
+            InvalidSignal::Error,
+        ] {
+            assert_eq!(policy.resolve(-6.0, 10.0), (-6.0, false));
+            assert_eq!(policy.resolve(-INFINITY, 10.0), (-INFINITY, false));
+            let mut state = MeterState::default();
+            for signal in [-6.0, f32::NAN, INFINITY] {
+                let (signal, _) = policy.resolve(signal, 10.0);
+                state.next_peak(SignalKind::Mono(signal), 4);
+            }
+            let held = if policy == InvalidSignal::Clamp {
+                10.0
+            } else {
+                -6.0
+            };
+            assert_eq!(state.peaks(), Some(vec![held]));
+        }
+    }
+
     #[test]
     fn neg_inf_is_normalised_as_0() {
         let increments = vec![-10.0, 0.0];
-        assert_eq!(normalised_from_value(-INFINITY, increments), 0.0);
+        assert_eq!(normalised_from_value(-INFINITY, &increments, None), 0.0);
     }
 
     #[test]
     fn zero_norm_becomes_neg_inf() {
         let increments = vec![-10.0, 0.0];
-        assert_eq!(value_from_normalised(0.0, increments), -INFINITY);
+        assert_eq!(value_from_normalised(0.0, &increments, None), -INFINITY);
     }
 
     #[test]
@@ -535,7 +3680,7 @@ mod test {
         let increments = vec![-20.0, -3.0, 0.0, 2.0, 10.0];
         let normals: Vec<_> = increments
             .iter()
-            .map(|value| normalised_from_value(*value, increments.clone()))
+            .map(|value| normalised_from_value(*value, &increments, None))
             .collect();
         assert_eq!(normals, [0.0, 0.25, 0.5, 0.75, 1.0])
     }
@@ -546,7 +3691,7 @@ mod test {
         let midpoints = vec![-13.0, -3.0, 1.0, 6.0];
         let normals: Vec<_> = midpoints
             .iter()
-            .map(|value| normalised_from_value(*value, increments.clone()))
+            .map(|value| normalised_from_value(*value, &increments, None))
             .collect();
         assert_eq!(normals, [0.125, 0.375, 0.625, 0.875])
     }
@@ -557,7 +3702,7 @@ mod test {
         let increments = vec![-20.0, -3.0, 0.0, 2.0, 10.0];
         let values: Vec<_> = normals
             .iter()
-            .map(|normal| value_from_normalised(*normal, increments.clone()))
+            .map(|normal| value_from_normalised(*normal, &increments, None))
             .collect();
         assert_eq!(values, [-INFINITY, -3.0, 0.0, 2.0, 10.0]);
     }
@@ -568,8 +3713,609 @@ mod test {
         let increments = vec![-20.0, -6.0, 0.0, 2.0, 10.0];
         let values: Vec<_> = normals
             .iter()
-            .map(|normal| value_from_normalised(*normal, increments.clone()))
+            .map(|normal| value_from_normalised(*normal, &increments, None))
             .collect();
         assert_eq!(values, [-13.0, -3.0, 1.0, 6.0]);
     }
+
+    #[test]
+    fn weighted_segments_take_their_share_of_travel() {
+        let increments = [-30.0, -10.0, 0.0, 10.0];
+        let weights = [1.0, 1.0, 2.0];
+        let normals: Vec<_> = increments
+            .iter()
+            .map(|value| normalised_from_value(*value, &increments, Some(&weights)))
+            .collect();
+        assert_eq!(normals, [0.0, 0.25, 0.5, 1.0]);
+        assert_eq!(
+            value_from_normalised(0.75, &increments, Some(&weights)),
+            5.0
+        );
+    }
+
+    #[test]
+    fn cached_breakpoints_match_weighted_segments() {
+        let increments = [-30.0, -10.0, 0.0, 10.0];
+        let weights = [1.0, 1.0, 2.0];
+        assert_eq!(breakpoints(&increments, &weights), [0.0, 0.25, 0.5, 1.0]);
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0)
+            .increments(&increments[..])
+            .segment_weights(&weights[..]);
+        for value in [-20.0, -10.0, -5.0, 5.0, 10.0] {
+            let normalised = normalised_from_value(value, &increments, Some(&weights));
+            assert_eq!(fader.normalise(value), normalised);
+            assert_eq!(fader.denormalise(normalised), value);
+        }
+    }
+
+    #[test]
+    fn dmx_fader_bottom_is_zero() {
+        let mut level: u8 = 100;
+        let mut fader = Fader::dmx(&mut level, 0.0);
+        fader.set_level(fader.denormalise(0.0) as f64);
+        assert_eq!(fader.get_level(), 0.0);
+        assert_eq!(fader.level_text(127.5), "50%");
+    }
+
+    #[test]
+    #[should_panic = "The bipolar range must be non-zero and finite."]
+    fn bipolar_range_of_zero_panics() {
+        let mut level: f32 = 0.0;
+        let _ = Fader::mono(&mut level, 0.0).bipolar(0.0);
+    }
+
+    #[test]
+    fn bipolar_fader_centres_neutral() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0).bipolar(12.0);
+        assert_eq!(fader.normalise(0.0), 0.5);
+        assert_eq!(fader.denormalise(0.0), -12.0);
+    }
+
+    #[test]
+    fn nudge_steps_off_and_on_to_neg_infinity() {
+        let mut level: f32 = -99.5;
+        let mut fader = Fader::mono(&mut level, 0.0);
+        fader.nudge_level(-1.0);
+        assert_eq!(fader.get_level(), -INFINITY as f64);
+        fader.nudge_level(1.0);
+        assert_eq!(fader.get_level(), -100.0);
+        fader.set_level(9.5);
+        fader.nudge_level(1.0);
+        assert_eq!(fader.get_level(), 10.0);
+    }
+
+    #[test]
+    fn extended_top_segment_drags_from_the_actual_level() {
+        let mut level: f32 = 12.0;
+        let mut fader = Fader::mono(&mut level, 0.0).out_of_range(OutOfRange::Extend);
+        // The top segment runs from 0 to +10 over the top quarter.
+        assert!((fader.normalise_extended(12.0) - 1.05).abs() < 1e-6);
+        assert!((fader.denormalise_extended(1.05) - 12.0).abs() < 1e-4);
+        assert_eq!(fader.normalise_extended(5.0), fader.normalise_level(5.0));
+        fader.nudge_level(1.0);
+        assert_eq!(fader.get_level(), 12.0);
+        fader.nudge_level(-1.0);
+        assert_eq!(fader.get_level(), 11.0);
+    }
+
+    #[test]
+    fn clamp_stored_writes_back_once_shown() {
+        let ctx = Context::default();
+        let mut level: f32 = 12.0;
+        let mut changed = false;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let fader = Fader::mono(&mut level, 0.0).out_of_range(OutOfRange::ClampStored);
+                changed = ui.add(fader).changed();
+            });
+        });
+        assert!(changed);
+        assert_eq!(level, 10.0);
+    }
+
+    #[test]
+    fn drag_conflicts_follow_the_policy() {
+        let ctx = Context::default();
+        let id = Id::new("fader");
+        for (policy, expected, owned) in [
+            (DragConflict::Relative, -20.0, true),
+            (DragConflict::DragWins, -6.0, true),
+            (DragConflict::ExternalWins, -20.0, false),
+        ] {
+            let mut level: f32 = -20.0;
+            let mut fader = Fader::mono(&mut level, 0.0).drag_conflict(policy);
+            ctx.data_mut(|data| data.insert_temp(drag_owner_id(id), DragOwner::Drag(-6.0)));
+            assert_eq!(fader.drag_conflict_interaction(&ctx, id, true), owned);
+            assert_eq!(fader.get_level(), expected);
+            // A drag that gave way stays out of it until it ends.
+            assert_eq!(fader.drag_conflict_interaction(&ctx, id, true), owned);
+            assert_eq!(fader.drag_conflict_interaction(&ctx, id, false), owned);
+            assert!(fader.drag_conflict_interaction(&ctx, id, false));
+        }
+    }
+
+    #[test]
+    fn drags_on_the_meter_move_the_fader() {
+        let ctx = Context::default();
+        let mut rect = Rect::NOTHING;
+        let mut level: f32 = 0.0;
+        for frame in 0..5 {
+            let mut input = egui::RawInput::default();
+            if frame > 0 {
+                // Press on the meter, well to the right of the rail, and drag up.
+                let start = pos2(rect.right() - 2.0, rect.center().y);
+                let pos = start - vec2(0.0, 10.0 * (frame - 1) as f32);
+                input.events.push(egui::Event::PointerMoved(pos));
+                if frame == 1 {
+                    input.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed: true,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+            }
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect = ui.add(Fader::mono(&mut level, 0.0)).rect;
+                });
+            });
+        }
+        assert!(level > 0.0);
+    }
+
+    #[test]
+    fn output_rects_follow_the_text_layout() {
+        let ctx = Context::default();
+        let mut outputs = Vec::new();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut level: f32 = 0.0;
+                for text_size in [10.0, 20.0] {
+                    outputs.push(Fader::mono(&mut level, 0.0).text_size(text_size).show(ui));
+                }
+                outputs.push(Fader::mono(&mut level, 0.0).show_text(false).show(ui));
+            });
+        });
+        let [small, large, none] = &outputs[..] else {
+            panic!();
+        };
+        let unused =
+            |output: &FaderOutput| output.response.rect.height() - output.rects.meter.height();
+        assert_eq!(unused(small), 25.0);
+        assert_eq!(unused(large), 50.0);
+        assert_eq!(none.rects.meter.y_range(), none.response.rect.y_range());
+    }
+
+    #[test]
+    fn shift_drags_move_the_selection() {
+        let mut selection = FaderSelection::default();
+        selection.set_selected(0, true);
+        selection.set_selected(1, true);
+        let mut moved = Vec::new();
+        for modifiers in [Modifiers::NONE, Modifiers::SHIFT] {
+            let ctx = Context::default();
+            let mut levels = [-10.0_f32, -10.0];
+            let mut rect = Rect::NOTHING;
+            for frame in 0..4 {
+                let mut input = egui::RawInput {
+                    modifiers,
+                    ..Default::default()
+                };
+                if frame > 0 {
+                    let pos = pos2(rect.left() + 2.0, rect.center().y - 10.0 * frame as f32);
+                    input.events.push(egui::Event::PointerMoved(pos));
+                    if frame == 1 {
+                        input.events.push(egui::Event::PointerButton {
+                            pos,
+                            button: egui::PointerButton::Primary,
+                            pressed: true,
+                            modifiers,
+                        });
+                    }
+                }
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        ui.horizontal(|ui| {
+                            for (index, level) in levels.iter_mut().enumerate() {
+                                let fader =
+                                    Fader::mono(level, 0.0).selection(&mut selection, index);
+                                let response = ui.push_id(index, |ui| ui.add(fader)).inner;
+                                if index == 0 {
+                                    rect = response.rect;
+                                }
+                            }
+                        });
+                    });
+                });
+                selection.apply(&mut levels, &DEFAULT_INCREMENTS, None);
+            }
+            assert!(levels[0] > -10.0);
+            moved.push(levels[1] > -10.0);
+        }
+        assert_eq!(moved, [false, true]);
+    }
+
+    #[test]
+    fn gestures_only_follow_drags_on_the_surface() {
+        let ctx = Context::default();
+        let mut rect = Rect::NOTHING;
+        let mut level: f32 = 0.0;
+        let gestures = RefCell::new(Vec::new());
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                rect = ui.add(Fader::mono(&mut level, 0.0)).rect;
+            });
+        });
+        // Drag from the meter, which isn't part of the surface, then from the rail.
+        for x in [-2.0, 2.0] {
+            for frame in 0..4 {
+                let mut input = egui::RawInput::default();
+                let start = if x < 0.0 {
+                    pos2(rect.right() + x, rect.center().y)
+                } else {
+                    pos2(rect.left() + x, rect.center().y)
+                };
+                let pos = start - vec2(0.0, 10.0 * frame as f32);
+                input.events.push(egui::Event::PointerMoved(pos));
+                if frame == 0 || frame == 3 {
+                    input.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed: frame == 0,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        let fader = Fader::mono(&mut level, 0.0)
+                            .drag_anywhere(false)
+                            .on_gesture_start(|_| gestures.borrow_mut().push("start"))
+                            .on_gesture_end(|_| gestures.borrow_mut().push("end"));
+                        rect = ui.add(fader).rect;
+                    });
+                });
+            }
+        }
+        assert_eq!(gestures.into_inner(), ["start", "end"]);
+    }
+
+    #[test]
+    fn f64_levels_keep_their_precision() {
+        let start = -6.000_000_123_f64;
+        let mut level = start;
+        let mut highest = start;
+        let ctx = Context::default();
+        let mut rect = Rect::NOTHING;
+        // Drag up and back down again in one gesture.
+        for (frame, offset) in [0.0, 0.0, -10.0, -20.0, -10.0, 0.0].into_iter().enumerate() {
+            let mut input = egui::RawInput::default();
+            if frame > 0 {
+                let pos = rect.center() + vec2(0.0, offset);
+                input.events.push(egui::Event::PointerMoved(pos));
+                if frame == 1 {
+                    input.events.push(egui::Event::PointerButton {
+                        pos,
+                        button: egui::PointerButton::Primary,
+                        pressed: true,
+                        modifiers: Modifiers::NONE,
+                    });
+                }
+            }
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    rect = ui.add(Fader::mono(&mut level, 0.0)).rect;
+                });
+            });
+            highest = highest.max(level);
+        }
+        assert!(highest > start + 1.0);
+        assert!((level - start).abs() < 1e-9, "{level}");
+
+        let mut fader = Fader::mono(&mut level, 0.0);
+        fader.nudge_level(1.0);
+        assert!((fader.get_level() - (start + 1.0)).abs() < 1e-12);
+
+        let mut normalised = 0.123_456_789_012_f64;
+        let mut fader = Fader::mono(&mut normalised, 0.0).normalized(true);
+        let level = fader.get_level();
+        fader.set_level(level);
+        drop(fader);
+        assert!((normalised - 0.123_456_789_012).abs() < 1e-12);
+    }
+
+    #[test]
+    fn label_galleys_are_laid_out_once() {
+        let ctx = Context::default();
+        let mut galleys = Vec::new();
+        for colour in [Color32::RED, Color32::RED, Color32::GREEN] {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let labels = || [(0, "-10".to_owned(), FontId::proportional(10.0))].into_iter();
+                    galleys.push(cached_galleys(ui, Id::new("labels"), labels, colour));
+                });
+            });
+        }
+        assert!(Arc::ptr_eq(&galleys[0], &galleys[1]));
+        assert!(!Arc::ptr_eq(&galleys[1], &galleys[2]));
+    }
+
+    #[test]
+    fn typed_levels_are_parsed_and_clamped() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0);
+        assert_eq!(fader.parse_level(" -6.5 "), Some(-6.5));
+        assert_eq!(fader.parse_level("20"), Some(10.0));
+        assert_eq!(fader.parse_level("-200"), Some(-INFINITY as f64));
+        assert_eq!(fader.parse_level("-∞"), Some(-INFINITY as f64));
+        assert_eq!(fader.parse_level("loud"), None);
+    }
+
+    #[test]
+    fn any_of_the_modifiers_enable_fine_drag() {
+        assert!(any_modifier_held(
+            Modifiers::SHIFT,
+            FADER_FINE_DRAG_MODIFIERS
+        ));
+        assert!(any_modifier_held(
+            Modifiers::CTRL | Modifiers::SHIFT,
+            Modifiers::SHIFT
+        ));
+        assert!(!any_modifier_held(Modifiers::CTRL, Modifiers::SHIFT));
+        assert!(!any_modifier_held(Modifiers::ALT, Modifiers::NONE));
+    }
+
+    #[test]
+    fn get_set_closure_receives_levels() {
+        let mut stored = Vec::new();
+        let mut fader = Fader::from_get_set(
+            |level: Option<f32>| {
+                if let Some(level) = level {
+                    stored.push(level);
+                }
+                stored.last().copied().unwrap_or(0.0)
+            },
+            -20.0,
+        );
+        fader.set_level(-6.0);
+        assert_eq!(fader.get_level(), -6.0);
+        drop(fader);
+        assert_eq!(stored, [-6.0]);
+    }
+
+    #[test]
+    fn gestures_report_start_and_end_levels() {
+        let gestures = RefCell::new(Vec::new());
+        let mut level: f32 = -6.0;
+        let mut fader = Fader::mono(&mut level, 0.0)
+            .on_gesture_start(|level| gestures.borrow_mut().push(("start", level)))
+            .on_gesture_end(|level| gestures.borrow_mut().push(("end", level)));
+        fader.gesture_start();
+        fader.set_to_neutral();
+        fader.gesture_end();
+        drop(fader);
+        assert_eq!(gestures.into_inner(), [("start", -6.0), ("end", 0.0)]);
+    }
+
+    #[test]
+    fn accessibility_text_replaces_readout() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0).suffix(" dB");
+        assert_eq!(fader.accessible_level_text(-6.5), "-6.5 dB");
+        let fader = fader.accessibility_text(|level| format!("Level {level} decibels"));
+        assert_eq!(fader.accessible_level_text(-6.5), "Level -6.5 decibels");
+    }
+
+    #[test]
+    fn held_peak_is_read_from_memory() {
+        let ctx = Context::default();
+        let id = Id::new("fader");
+        assert_eq!(Fader::peak_in_memory(&ctx, id), None);
+        let mut state = MeterState::default();
+        state.next_peak(SignalKind::Stereo([-6.0, -3.0]), 4);
+        state.next_peak(SignalKind::Stereo([-12.0, -12.0]), 4);
+        ctx.data_mut(|data| data.insert_temp(peak_id(id), state));
+        assert_eq!(Fader::peak_in_memory(&ctx, id), Some(vec![-6.0, -3.0]));
+    }
+
+    #[test]
+    fn reduced_motion_shows_instantaneous_peak() {
+        let ctx = Context::default();
+        Fader::set_reduced_motion(&ctx, true);
+        let mut id = Id::NULL;
+        for signal in [-3.0, -20.0] {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let mut level: f32 = 0.0;
+                    id = ui.add(Fader::mono(&mut level, signal)).id;
+                });
+            });
+        }
+        assert_eq!(Fader::peak_in_memory(&ctx, id), Some(vec![-20.0]));
+    }
+
+    #[test]
+    fn owned_meter_state_stays_out_of_memory() {
+        let ctx = Context::default();
+        let mut meter = MeterState::default();
+        let mut id = Id::NULL;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut level: f32 = 0.0;
+                id = ui
+                    .add(Fader::mono(&mut level, -6.0).meter_state(&mut meter))
+                    .id;
+            });
+        });
+        assert_eq!(meter.peaks(), Some(vec![-6.0]));
+        assert_eq!(Fader::peak_in_memory(&ctx, id), None);
+    }
+
+    #[test]
+    fn without_text_the_meter_fills_the_fader() {
+        let mut level: f32 = 0.0;
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(50.0, 200.0));
+        let fader = Fader::mono(&mut level, 0.0).show_text(false);
+        assert_eq!(fader.content_rect(rect), rect);
+        assert_eq!(fader.rail_fraction(), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn meter_only_repaints_while_moving() {
+        let mut last = Vec::new();
+        assert!(meter_moved(&mut last, [10.0, 10.0].into_iter(), 0.5));
+        assert!(meter_moved(&mut last, [12.0, 12.0].into_iter(), 0.5));
+        assert!(!meter_moved(&mut last, [12.2, 12.2].into_iter(), 0.5));
+        assert_eq!(last, [12.2, 12.2]);
+        // A held peak is still to fall.
+        assert!(meter_moved(&mut last, [5.0, 10.0].into_iter(), 0.5));
+        assert!(meter_moved(&mut last, [5.0].into_iter(), 0.5));
+        assert_eq!(last, [5.0]);
+    }
+
+    #[test]
+    fn reset_clears_peaks_and_clip_lights() {
+        let ctx = Context::default();
+        let ids = [Id::new("left"), Id::new("right")];
+        let clip_lit = |id: Id| {
+            ctx.data(|data| data.get_temp::<MeterState>(peak_id(id)))
+                .is_some_and(|state| state.clip_lit())
+        };
+        for id in ids {
+            let mut state = MeterState::default();
+            state.next_peak(SignalKind::Mono(6.0), 4);
+            state.update_clip(true, false);
+            ctx.data_mut(|data| data.insert_temp(peak_id(id), state));
+        }
+        Fader::reset_peaks(&ctx, ids[0]);
+        assert_eq!(Fader::peak_in_memory(&ctx, ids[0]), None);
+        assert!(!clip_lit(ids[0]));
+        assert!(clip_lit(ids[1]));
+        Fader::reset_all_peaks(&ctx);
+        assert_eq!(Fader::peak_in_memory(&ctx, ids[1]), None);
+        assert!(!clip_lit(ids[1]));
+    }
+
+    #[test]
+    fn tooltip_shows_precise_level_and_signal() {
+        let mut level: f32 = 0.0;
+        let mut fader = Fader::stereo(&mut level, [-12.0, -6.0]).tooltip_signal(true);
+        fader.peak = Some(SignalKind::Stereo([-3.0, -1.5]));
+        assert_eq!(
+            fader.tooltip_text(-6.125),
+            "-6.125\nSignal: -12.00 / -6.00\nPeak: -3.00 / -1.50"
+        );
+    }
+
+    #[test]
+    fn positions_snap_to_nearest_increment() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0);
+        let range = Rangef::new(100.0, 0.0);
+        assert_eq!(fader.snap_to_increment(40.0, range), -10.0);
+        assert_eq!(fader.snap_to_increment(90.0, range), -INFINITY);
+        assert_eq!(fader.snap_to_increment(-5.0, range), 10.0);
+    }
+
+    #[test]
+    fn custom_formatter_and_parser_are_used() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0)
+            .custom_formatter(|level| format!("{level:.2}").replace('.', ","))
+            .custom_parser(|text| text.replace(',', ".").parse().ok());
+        assert_eq!(fader.level_text(-6.5), "-6,50");
+        assert_eq!(fader.parse_level("-6,5"), Some(-6.5));
+    }
+
+    #[test]
+    fn suffix_is_shown_and_ignored_when_parsing() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0).suffix(" dB");
+        assert_eq!(fader.level_text(-6.0), "-6.0 dB");
+        assert_eq!(fader.parse_level("-6.0 dB"), Some(-6.0));
+        assert_eq!(fader.parse_level("-3dB"), Some(-3.0));
+        assert_eq!(fader.parse_level("-3"), Some(-3.0));
+    }
+
+    #[test]
+    fn readout_uses_decimals() {
+        let (mut a, mut b, mut c) = (0.0_f32, 0.0_f32, 0.0_f32);
+        let fader = Fader::mono(&mut a, 0.0).decimals(2);
+        assert_eq!(fader.level_text(-6.125), "-6.12");
+        let fader = Fader::mono(&mut b, 0.0).decimals(0);
+        assert_eq!(fader.level_text(-6.4), "-6");
+        let fader = Fader::mono(&mut c, 0.0)
+            .display_mode(DisplayMode::Percent)
+            .decimals(1);
+        assert_eq!(fader.level_text(-10.0), "50.0%");
+    }
+
+    #[test]
+    fn labels_use_label_formatter() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0);
+        assert_eq!(fader.label_text(-100.0, 0), "-∞");
+        assert_eq!(fader.label_text(-10.0, 2), "-10");
+        let fader = fader.label_formatter(|value, _| match value {
+            0.0 => "U".to_owned(),
+            _ => format!("{}", value.abs()),
+        });
+        assert_eq!(fader.label_text(-100.0, 0), "100");
+        assert_eq!(fader.label_text(0.0, 3), "U");
+    }
+
+    #[test]
+    fn neg_infinity_uses_infinity_text() {
+        let (mut a, mut b) = (0.0_f32, 0.0_f32);
+        let fader = Fader::mono(&mut a, 0.0);
+        assert_eq!(fader.level_text(f64::NEG_INFINITY), "-∞");
+        let fader = Fader::mono(&mut b, 0.0)
+            .infinity_text("Off")
+            .infinity_label(false);
+        assert_eq!(fader.level_text(f64::NEG_INFINITY), "Off");
+        assert_eq!(fader.parse_level("Off"), Some(-INFINITY as f64));
+        assert_eq!(fader.label_text(-100.0, 0), "-100");
+    }
+
+    #[test]
+    fn integer_levels_round_and_stop_at_first_increment() {
+        let mut level: i32 = 0;
+        let mut fader = Fader::mono(&mut level, 0.0);
+        fader.set_level(-6.6);
+        assert_eq!(fader.get_level(), -7.0);
+        fader.set_level(-INFINITY as f64);
+        assert_eq!(fader.get_level(), -100.0);
+    }
+
+    #[test]
+    fn percent_display_shows_fader_position() {
+        let mut level: f32 = 0.0;
+        let fader = Fader::mono(&mut level, 0.0).display_mode(DisplayMode::Percent);
+        assert_eq!(fader.level_text(-10.0), "50%");
+        assert_eq!(fader.level_text(-INFINITY as f64), "0%");
+    }
+
+    #[test]
+    fn normalized_fader_stores_normalised_level() {
+        let mut level: f32 = 0.0;
+        let mut fader = Fader::mono(&mut level, 0.0).normalized(true);
+        fader.set_level(-10.0);
+        assert_eq!(fader.get_level(), -10.0);
+        drop(fader);
+        assert_eq!(level, 0.5);
+    }
+
+    #[test]
+    fn touch_target_grows_hit_area_of_thin_faders() {
+        let mut level: f32 = 0.0;
+        let rect = Rect::from_min_size(Pos2::ZERO, vec2(50.0, 200.0));
+        let fader = Fader::mono(&mut level, 0.0).hit_margin(2.0);
+        assert_eq!(fader.touch_margin(rect), Vec2::splat(2.0));
+        let fader = fader.min_touch_target(44.0);
+        let margin = fader.touch_margin(rect);
+        assert_eq!(margin.x, 17.0);
+        assert_eq!(margin.y, 18.0);
+    }
 }
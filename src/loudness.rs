@@ -0,0 +1,255 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use egui::{Align2, FontId, Pos2, Rect, Response, Sense, Stroke, Ui, Widget, epaint, lerp, vec2};
+
+use crate::{FaderStyle, normalised_from_value};
+
+const LOUDNESS_INCREMENTS: [f32; 6] = [-60.0, -40.0, -30.0, -23.0, -14.0, 0.0];
+
+/// Loudness measurements in LUFS, e.g. from an EBU R 128 meter.
+/// Use `NEG_INFINITY` for measurements that aren't available yet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Loudness {
+    /// Loudness over the last 400 ms.
+    pub momentary: f32,
+
+    /// Loudness over the last 3 s.
+    pub short_term: f32,
+
+    /// Gated loudness since the measurement started.
+    pub integrated: f32,
+}
+
+impl Default for Loudness {
+    fn default() -> Self {
+        Self {
+            momentary: f32::NEG_INFINITY,
+            short_term: f32::NEG_INFINITY,
+            integrated: f32::NEG_INFINITY,
+        }
+    }
+}
+
+/// Bars of the momentary, short-term and integrated loudness with a graph of the recent
+/// short-term loudness.
+///
+/// The meter only displays measurements, feed it from the loudness backend of your choice.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let loudness = egui_fader::Loudness {
+///     momentary: -18.0,
+///     short_term: -20.5,
+///     integrated: -23.1,
+/// };
+/// ui.add(egui_fader::LoudnessMeter::new(loudness).target(-16.0));
+/// # });
+/// ```
+pub struct LoudnessMeter<'a> {
+    loudness: Loudness,
+    increments: Cow<'a, [f32]>,
+    target: f32,
+    height: Option<f32>,
+    history_width: f32,
+    history_length: usize,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> LoudnessMeter<'a> {
+    /// Creates a loudness meter showing these measurements.
+    pub fn new(loudness: Loudness) -> Self {
+        Self {
+            loudness,
+            increments: Cow::Borrowed(&LOUDNESS_INCREMENTS),
+            target: -23.0,
+            height: None,
+            history_width: 100.0,
+            history_length: 300,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the LUFS scale, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set the target loudness marked across the bars and graph. Default: `-23.0` LUFS.
+    #[inline]
+    pub fn target(mut self, target: f32) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Set the height of the meter in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width of the history graph in points, 0 hides it. Default: `100.0`.
+    #[inline]
+    pub fn history_width(mut self, history_width: f32) -> Self {
+        self.history_width = history_width;
+        self
+    }
+
+    /// Set the number of frames of short-term loudness kept in the history. Default: `300`.
+    #[inline]
+    pub fn history_length(mut self, history_length: usize) -> Self {
+        self.history_length = history_length;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the meter. The `channel`, `signal`, `peak` (target) and `label_text`
+    /// colours are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, loudness: f32) -> f32 {
+        normalised_from_value(loudness, &self.increments, None)
+    }
+
+    fn y_from_loudness(&self, rect: Rect, loudness: f32) -> f32 {
+        lerp(rect.bottom()..=rect.top(), self.normalise(loudness))
+    }
+
+    fn next_history(&self, ui: &Ui, response: &Response) -> VecDeque<f32> {
+        let id = response.id.with("loudness");
+        ui.data_mut(|data| {
+            let history = data.get_temp_mut_or_default::<VecDeque<f32>>(id);
+            history.push_back(self.loudness.short_term);
+            while history.len() > self.history_length {
+                history.pop_front();
+            }
+            history.clone()
+        })
+    }
+
+    fn bars_ui(&self, ui: &Ui, rect: Rect) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let channel_colour = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        let signal_colour = self
+            .style
+            .signal
+            .unwrap_or(visuals.widgets.active.fg_stroke.color);
+        let text_colour = self.style.label_text.unwrap_or(visuals.text_color());
+        let bars = [
+            ("M", self.loudness.momentary),
+            ("S", self.loudness.short_term),
+            ("I", self.loudness.integrated),
+        ];
+        let bar_width = rect.width() / bars.len() as f32;
+        let (meter_rect, label_rect) = rect.split_top_bottom_at_y(rect.bottom() - self.text_size);
+        for (index, (label, loudness)) in bars.into_iter().enumerate() {
+            let left = lerp(
+                meter_rect.left()..=meter_rect.right(),
+                index as f32 / bars.len() as f32,
+            );
+            let bar_rect = Rect::from_min_size(
+                Pos2::new(left, meter_rect.top()),
+                vec2(bar_width, meter_rect.height()),
+            )
+            .shrink2(vec2(0.15 * bar_width, 0.0));
+            ui.painter().rect_filled(bar_rect, corner, channel_colour);
+            let top = self.y_from_loudness(bar_rect, loudness);
+            let fill = Rect::from_x_y_ranges(bar_rect.x_range(), top..=bar_rect.bottom());
+            ui.painter().rect_filled(fill, corner, signal_colour);
+            ui.painter().text(
+                Pos2::new(bar_rect.center().x, label_rect.center().y),
+                Align2::CENTER_CENTER,
+                label,
+                FontId::proportional(self.text_size),
+                text_colour,
+            );
+        }
+        self.target_ui(ui, meter_rect);
+    }
+
+    fn history_ui(&self, ui: &Ui, rect: Rect, history: &VecDeque<f32>) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let rect = rect.with_max_y(rect.bottom() - self.text_size);
+        let channel_colour = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        ui.painter().rect_filled(rect, corner, channel_colour);
+        self.target_ui(ui, rect);
+
+        // Newest on the right, scrolling left.
+        let step = rect.width() / self.history_length.max(2) as f32;
+        let points: Vec<Pos2> = history
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(age, &loudness)| {
+                Pos2::new(
+                    rect.right() - age as f32 * step,
+                    self.y_from_loudness(rect, loudness),
+                )
+            })
+            .collect();
+        if points.len() < 2 {
+            return;
+        }
+        let colour = self
+            .style
+            .signal
+            .unwrap_or(visuals.widgets.active.fg_stroke.color);
+        ui.painter()
+            .add(epaint::PathShape::line(points, Stroke::new(1.5, colour)));
+    }
+
+    fn target_ui(&self, ui: &Ui, rect: Rect) {
+        let colour = self.style.peak.unwrap_or(ui.visuals().warn_fg_color);
+        let y = self.y_from_loudness(rect, self.target);
+        ui.painter()
+            .hline(rect.x_range(), y, Stroke::new(1.0, colour));
+    }
+}
+
+impl Widget for LoudnessMeter<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let bars_width = 4.0 * self.text_size;
+        let height = self.height.unwrap_or_else(|| ui.spacing().slider_width);
+        let gap = if self.history_width > 0.0 {
+            0.5 * self.text_size
+        } else {
+            0.0
+        };
+        let size = vec2(bars_width + gap + self.history_width, height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let history = self.next_history(ui, &response);
+        if ui.is_rect_visible(rect) {
+            let (bars, history_rect) = rect.split_left_right_at_x(rect.left() + bars_width);
+            self.bars_ui(ui, bars);
+            if self.history_width > 0.0 {
+                let history_rect = history_rect.with_min_x(history_rect.left() + gap);
+                self.history_ui(ui, history_rect, &history);
+            }
+        }
+        response
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+use crate::Loudness;
+
+/// Samples per gating block are counted in steps of 100 ms.
+const STEPS_PER_MOMENTARY: usize = 4;
+const STEPS_PER_SHORT_TERM: usize = 30;
+
+/// A biquad filter in direct form II transposed.
+#[derive(Copy, Clone, Debug, Default)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    state: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.state[0];
+        self.state[0] = self.b[1] * input - self.a[0] * output + self.state[1];
+        self.state[1] = self.b[2] * input - self.a[1] * output;
+        output
+    }
+}
+
+/// The K-weighting of ITU-R BS.1770: a high shelf for the head followed by a high pass.
+fn k_weighting(sample_rate: f64) -> [Biquad; 2] {
+    let k = (PI * 1_681.974_450_955_533 / sample_rate).tan();
+    let q = 0.707_175_236_955_419_6;
+    let vh = 10_f64.powf(3.999_843_853_973_347 / 20.0);
+    let vb = vh.powf(0.499_666_774_154_541_6);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad {
+        b: [
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+        ],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        state: [0.0; 2],
+    };
+    let k = (PI * 38.135_470_876_024_44 / sample_rate).tan();
+    let q = 0.500_327_037_323_877_3;
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad {
+        b: [1.0, -2.0, 1.0],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        state: [0.0; 2],
+    };
+    [shelf, high_pass]
+}
+
+fn loudness_from_energy(energy: f64) -> f32 {
+    (-0.691 + 10.0 * energy.log10()) as f32
+}
+
+/// Measures EBU R 128 loudness from blocks of samples to feed a [`crate::LoudnessMeter`].
+///
+/// Every channel is weighted equally, which suits mono and stereo. Integrated loudness keeps
+/// one value per 100 ms since the last [`Self::reset`].
+///
+/// ```
+/// let mut analyser = egui_fader::LoudnessAnalyser::new(48_000.0, 2);
+/// # let my_interleaved_block = [0.0_f32; 960];
+/// analyser.add_frames(&my_interleaved_block);
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui_fader::LoudnessMeter::new(analyser.loudness()));
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoudnessAnalyser {
+    channels: usize,
+    filters: Vec<[Biquad; 2]>,
+    step_length: usize,
+    step_position: usize,
+    step_energy: f64,
+    /// The mean square of the last 3 s, one per 100 ms step.
+    steps: VecDeque<f64>,
+    /// The mean square of every 400 ms gating block, overlapping by 300 ms.
+    blocks: Vec<f64>,
+}
+
+impl LoudnessAnalyser {
+    /// Creates an analyser for interleaved samples with this many channels.
+    pub fn new(sample_rate: f32, channels: usize) -> Self {
+        let channels = channels.max(1);
+        Self {
+            channels,
+            filters: vec![k_weighting(sample_rate as f64); channels],
+            step_length: (sample_rate as usize / 10).max(1),
+            step_position: 0,
+            step_energy: 0.0,
+            steps: VecDeque::with_capacity(STEPS_PER_SHORT_TERM),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Add a block of interleaved samples, any trailing partial frame is ignored.
+    pub fn add_frames(&mut self, samples: &[f32]) {
+        for frame in samples.chunks_exact(self.channels) {
+            for ([shelf, high_pass], &sample) in self.filters.iter_mut().zip(frame) {
+                let weighted = high_pass.process(shelf.process(sample as f64));
+                self.step_energy += weighted * weighted;
+            }
+            self.step_position += 1;
+            if self.step_position == self.step_length {
+                self.end_step();
+            }
+        }
+    }
+
+    fn end_step(&mut self) {
+        if self.steps.len() == STEPS_PER_SHORT_TERM {
+            self.steps.pop_front();
+        }
+        self.steps
+            .push_back(self.step_energy / self.step_length as f64);
+        self.step_energy = 0.0;
+        self.step_position = 0;
+        if let Some(momentary) = self.mean_of_last_steps(STEPS_PER_MOMENTARY) {
+            self.blocks.push(momentary);
+        }
+    }
+
+    fn mean_of_last_steps(&self, count: usize) -> Option<f64> {
+        (self.steps.len() >= count)
+            .then(|| self.steps.iter().rev().take(count).sum::<f64>() / count as f64)
+    }
+
+    /// The loudness measured so far, `NEG_INFINITY` where there isn't enough audio yet.
+    pub fn loudness(&self) -> Loudness {
+        let measure = |steps| {
+            self.mean_of_last_steps(steps)
+                .map_or(f32::NEG_INFINITY, loudness_from_energy)
+        };
+        Loudness {
+            momentary: measure(STEPS_PER_MOMENTARY),
+            short_term: measure(STEPS_PER_SHORT_TERM),
+            integrated: self.integrated(),
+        }
+    }
+
+    /// Gated loudness: blocks under -70 LUFS are dropped, then blocks more than 10 LU under
+    /// the loudness of the rest.
+    fn integrated(&self) -> f32 {
+        let gated_mean = |threshold: f32| {
+            let (sum, count) = (self.blocks.iter())
+                .filter(|&&energy| loudness_from_energy(energy) > threshold)
+                .fold((0.0, 0), |(sum, count), energy| (sum + energy, count + 1));
+            (count > 0).then(|| sum / count as f64)
+        };
+        gated_mean(-70.0)
+            .and_then(|energy| gated_mean(loudness_from_energy(energy) - 10.0))
+            .map_or(f32::NEG_INFINITY, loudness_from_energy)
+    }
+
+    /// Start measuring again, e.g. at the start of a new programme.
+    pub fn reset(&mut self) {
+        for filters in &mut self.filters {
+            for filter in filters {
+                filter.state = [0.0; 2];
+            }
+        }
+        self.step_position = 0;
+        self.step_energy = 0.0;
+        self.steps.clear();
+        self.blocks.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sine_at_minus_23_dbfs_reads_minus_23_lufs() {
+        // EBU Tech 3341 case 1, a 1 kHz stereo sine at -23 dBFS.
+        let rate = 48_000.0;
+        let amplitude = 10_f32.powf(-23.0 / 20.0);
+        let samples: Vec<f32> = (0..20 * 48_000)
+            .flat_map(|index| {
+                let sample =
+                    amplitude * (std::f32::consts::TAU * 1000.0 * index as f32 / rate).sin();
+                [sample, sample]
+            })
+            .collect();
+        let mut analyser = LoudnessAnalyser::new(rate, 2);
+        for block in samples.chunks(1024) {
+            analyser.add_frames(block);
+        }
+        let loudness = analyser.loudness();
+        assert!((loudness.momentary + 23.0).abs() < 0.1, "{loudness:?}");
+        assert!((loudness.short_term + 23.0).abs() < 0.1, "{loudness:?}");
+        assert!((loudness.integrated + 23.0).abs() < 0.1, "{loudness:?}");
+    }
+
+    #[test]
+    fn silence_is_gated_out() {
+        let mut analyser = LoudnessAnalyser::new(44_100.0, 1);
+        assert_eq!(analyser.loudness(), Loudness::default());
+        analyser.add_frames(&[0.0; 44_100]);
+        let loudness = analyser.loudness();
+        assert_eq!(loudness.momentary, f32::NEG_INFINITY);
+        assert_eq!(loudness.integrated, f32::NEG_INFINITY);
+        analyser.reset();
+        assert_eq!(analyser.loudness(), Loudness::default());
+    }
+}
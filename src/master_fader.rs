@@ -0,0 +1,140 @@
+use egui::{Align, Layout, Response, Ui, Widget};
+
+use crate::{Fader, MeterBridge, Pan, amplitude_to_db, db_to_amplitude};
+
+const MASTER_TEXT_SIZE: f32 = 12.0;
+
+type ConfigureFader<'a> = Box<dyn 'a + FnOnce(Fader<'_>) -> Fader<'_>>;
+
+/// The master channel of a mixer: a wide stereo [`Fader`] with one handle for both channels and
+/// a bigger handle, a balance control and a meter of the mono sum beside the stereo meter.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let (mut my_level, mut my_balance): (f32, f32) = (0.0, 0.0);
+/// ui.add(egui_fader::MasterFader::new(&mut my_level, &mut my_balance, [-6.0, -8.0]));
+/// let [left_gain, right_gain] = egui_fader::MasterFader::balance_gains(my_balance);
+/// # });
+/// ```
+pub struct MasterFader<'a> {
+    level: &'a mut f32,
+    balance: &'a mut f32,
+    signal: [f32; 2],
+    show_sum: bool,
+    configure_fader: Option<ConfigureFader<'a>>,
+}
+
+impl<'a> MasterFader<'a> {
+    /// Creates a master fader. The balance is from `-1.0` (left) to `1.0` (right).
+    pub fn new(level: &'a mut f32, balance: &'a mut f32, signal: [f32; 2]) -> Self {
+        Self {
+            level,
+            balance,
+            signal,
+            show_sum: true,
+            configure_fader: None,
+        }
+    }
+
+    /// Set whether the meter of the mono sum is shown. Default: `true`.
+    #[inline]
+    pub fn show_sum(mut self, show_sum: bool) -> Self {
+        self.show_sum = show_sum;
+        self
+    }
+
+    /// Customise the fader, e.g. its increments or tooltip.
+    #[inline]
+    pub fn configure_fader(
+        mut self,
+        configure_fader: impl 'a + FnOnce(Fader<'_>) -> Fader<'_>,
+    ) -> Self {
+        self.configure_fader = Some(Box::new(configure_fader));
+        self
+    }
+
+    /// The linear gains `[left, right]` for a balance, turning down the opposite side.
+    pub fn balance_gains(balance: f32) -> [f32; 2] {
+        let balance = balance.clamp(-1.0, 1.0);
+        [(1.0 - balance).min(1.0), (1.0 + balance).min(1.0)]
+    }
+}
+
+impl Widget for MasterFader<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            level,
+            balance,
+            signal,
+            show_sum,
+            configure_fader,
+        } = self;
+        ui.with_layout(Layout::top_down(Align::Center), |ui| {
+            let mut response = ui.add(Pan::new(balance));
+            ui.horizontal_top(|ui| {
+                let fader = Fader::stereo(level, signal)
+                    .width(3.0 * ui.spacing().interact_size.x)
+                    .handle_size(1.5 * ui.spacing().interact_size.y)
+                    .text_size(MASTER_TEXT_SIZE);
+                let fader = match configure_fader {
+                    Some(configure_fader) => configure_fader(fader),
+                    None => fader,
+                };
+                let output = fader.show(ui);
+                if show_sum {
+                    let sum = [mono_sum(signal)];
+                    // Line the meter up with the meter of the fader, however its text is laid out.
+                    let meter = output.rects.meter;
+                    ui.vertical(|ui| {
+                        ui.add_space(meter.top() - output.response.rect.top());
+                        ui.add(MeterBridge::new(&sum).height(meter.height()));
+                    });
+                }
+                response |= output.response;
+            });
+            response
+        })
+        .inner
+    }
+}
+
+/// The level of the mono sum of two channel levels in dB.
+fn mono_sum([left, right]: [f32; 2]) -> f32 {
+    amplitude_to_db(0.5 * (db_to_amplitude(left) + db_to_amplitude(right)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn balance_turns_down_the_opposite_side() {
+        assert_eq!(MasterFader::balance_gains(0.0), [1.0, 1.0]);
+        assert_eq!(MasterFader::balance_gains(0.25), [0.75, 1.0]);
+        assert_eq!(MasterFader::balance_gains(-1.0), [1.0, 0.0]);
+    }
+
+    #[test]
+    fn master_fader_is_wider_than_a_channel() {
+        let ctx = egui::Context::default();
+        let mut widths = [0.0; 2];
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let (mut level, mut balance) = (0.0_f32, 0.0);
+                widths[0] = ui.add(Fader::stereo(&mut level, [0.0; 2])).rect.width();
+                let master = MasterFader::new(&mut level, &mut balance, [0.0; 2]).show_sum(false);
+                widths[1] = ui.add(master).rect.width();
+            });
+        });
+        assert!(widths[1] > widths[0]);
+    }
+
+    #[test]
+    fn mono_sum_averages_amplitudes() {
+        assert!((mono_sum([-6.0, -6.0]) + 6.0).abs() < 1e-4);
+        assert_eq!(
+            mono_sum([f32::NEG_INFINITY, f32::NEG_INFINITY]),
+            f32::NEG_INFINITY
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::borrow::Cow;
+
+use egui::{
+    Color32, CornerRadius, Mesh, Rangef, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+    pos2, vec2,
+};
+
+use crate::peak::PeakDetector;
+use crate::{DEFAULT_INCREMENTS, FaderStyle, InvalidSignal, normalised_from_value, reduced_motion};
+
+/// A bank of compact signal meters without controls, painted in a single allocation.
+///
+/// Each entry of `signals` is one meter, so an overview of many channels doesn't need a
+/// [`crate::Fader`] per channel. Peaks are held the same way as on the fader.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let signals = [-12.0, -30.0, -6.0, -100.0];
+/// ui.add(egui_fader::MeterBridge::new(&signals).meter_width(4.0));
+/// # });
+/// ```
+pub struct MeterBridge<'a> {
+    signals: &'a [f32],
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    height: Option<f32>,
+    meter_width: f32,
+    spacing: f32,
+    peak_buffer_size: usize,
+    batched: bool,
+    invalid_signal: InvalidSignal,
+    style: FaderStyle,
+}
+
+impl<'a> MeterBridge<'a> {
+    /// Creates a meter bridge with one meter per signal.
+    pub fn new(signals: &'a [f32]) -> Self {
+        Self {
+            signals,
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            height: None,
+            meter_width: 6.0,
+            spacing: 2.0,
+            peak_buffer_size: 60,
+            batched: false,
+            invalid_signal: InvalidSignal::default(),
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the meter scale, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set a relative weight for each segment between increments, see
+    /// [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the height of the meters in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the width of each meter in points. Default: `6.0`.
+    #[inline]
+    pub fn meter_width(mut self, meter_width: f32) -> Self {
+        self.meter_width = meter_width;
+        self
+    }
+
+    /// Set the gap between meters in points. Default: `2.0`.
+    #[inline]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the number of frames that will be stored in the peak buffer.
+    #[inline]
+    pub fn peak_buffer_size(mut self, peak_buffer_size: usize) -> Self {
+        self.peak_buffer_size = peak_buffer_size;
+        self
+    }
+
+    /// Paint every meter into one mesh of square cornered rectangles rather than adding a shape
+    /// for each part of each meter. Much cheaper to tessellate on pages with many meters, but
+    /// `meter_corner_radius` is ignored.
+    /// Default: `false`.
+    #[inline]
+    pub fn batched(mut self, batched: bool) -> Self {
+        self.batched = batched;
+        self
+    }
+
+    /// Set what a meter shows when its signal is NaN or `+∞`, see [`crate::Fader::invalid_signal`].
+    #[inline]
+    pub fn invalid_signal(mut self, invalid_signal: InvalidSignal) -> Self {
+        self.invalid_signal = invalid_signal;
+        self
+    }
+
+    /// Set the colours of the meters. The `channel`, `signal`, `signal_gradient` and `peak`
+    /// colours are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        normalised_from_value(value, &self.increments, self.segment_weights.as_deref())
+    }
+
+    /// Get the peaks from the recent buffers.
+    fn next_peaks(&self, ui: &Ui, response: &Response, signals: &[f32]) -> Vec<f32> {
+        let id = response.id.with("peak");
+        let peak_buffer_size = if reduced_motion(ui) {
+            1
+        } else {
+            self.peak_buffer_size
+        };
+        ui.memory_mut(|mem| {
+            let detectors = mem.data.get_temp_mut_or_default::<Vec<PeakDetector>>(id);
+            if detectors
+                .first()
+                .is_some_and(|detector| detector.buffer_size() != peak_buffer_size.max(1))
+            {
+                detectors.clear();
+            }
+            detectors.resize_with(signals.len(), || PeakDetector::new(peak_buffer_size));
+            detectors
+                .iter_mut()
+                .zip(signals)
+                .map(|(detector, signal)| detector.next(*signal))
+                .collect()
+        })
+    }
+
+    /// Paint one meter, passing each filled rectangle to `fill`.
+    fn meter_ui(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        signal: f32,
+        peak: f32,
+        fill: &mut dyn FnMut(Rect, CornerRadius, Color32),
+    ) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let channel_colour = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        fill(rect, corner, channel_colour);
+
+        let signal_y = rect.bottom() - rect.height() * self.normalise(signal);
+        let signal_rect = Rect::from_x_y_ranges(rect.x_range(), signal_y..=rect.bottom());
+        match &self.style.signal_gradient {
+            None => {
+                let colour = self
+                    .style
+                    .signal
+                    .unwrap_or(visuals.widgets.active.fg_stroke.color);
+                fill(signal_rect, corner, colour);
+            }
+            Some(gradient) => {
+                for (start, end, colour) in gradient.zones(|level| self.normalise(level)) {
+                    let zone_y = Rangef::new(
+                        rect.bottom() - rect.height() * end,
+                        rect.bottom() - rect.height() * start,
+                    );
+                    let zone_rect =
+                        signal_rect.intersect(Rect::from_x_y_ranges(rect.x_range(), zone_y));
+                    if zone_rect.is_positive() {
+                        fill(zone_rect, corner, colour);
+                    }
+                }
+            }
+        }
+
+        let peak_colour = self
+            .style
+            .peak
+            .unwrap_or(visuals.widgets.inactive.fg_stroke.color);
+        let peak_y = rect.bottom() - rect.height() * self.normalise(peak);
+        let peak_y = peak_y.clamp(rect.top() + 1.0, rect.bottom() - 1.0);
+        let peak_rect =
+            Rect::from_center_size(pos2(rect.center().x, peak_y), vec2(rect.width(), 2.0));
+        fill(peak_rect, CornerRadius::ZERO, peak_colour);
+    }
+}
+
+impl Widget for MeterBridge<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let count = self.signals.len() as f32;
+        let width = count * self.meter_width + (count - 1.0).max(0.0) * self.spacing;
+        let height = self.height.unwrap_or_else(|| ui.spacing().slider_width);
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        let top = self.increments[self.increments.len() - 1];
+        let (signals, invalid): (Vec<f32>, Vec<bool>) = (self.signals.iter())
+            .map(|&signal| self.invalid_signal.resolve(signal, top))
+            .unzip();
+        let peaks = self.next_peaks(ui, &response, &signals);
+        if ui.is_rect_visible(rect) {
+            let step = self.meter_width + self.spacing;
+            let mut mesh = Mesh::default();
+            let mut fill = |rect: Rect, corner: CornerRadius, colour: Color32| {
+                if self.batched {
+                    mesh.add_colored_rect(rect, colour);
+                } else {
+                    ui.painter().rect_filled(rect, corner, colour);
+                }
+            };
+            let meter_rect = |index: usize| {
+                let min = rect.left_top() + vec2(index as f32 * step, 0.0);
+                Rect::from_min_size(min, Vec2::new(self.meter_width, height))
+            };
+            for (index, (signal, peak)) in signals.iter().zip(peaks).enumerate() {
+                self.meter_ui(ui, meter_rect(index), *signal, peak, &mut fill);
+            }
+            if !mesh.is_empty() {
+                ui.painter().add(Shape::mesh(mesh));
+            }
+            if self.invalid_signal == InvalidSignal::Error {
+                let stroke = Stroke::new(1.5, ui.visuals().error_fg_color);
+                for index in (0..invalid.len()).filter(|&index| invalid[index]) {
+                    let rect = meter_rect(index);
+                    let cross = Rect::from_center_size(rect.center(), Vec2::splat(rect.width()));
+                    ui.painter()
+                        .line_segment([cross.left_top(), cross.right_bottom()], stroke);
+                    ui.painter()
+                        .line_segment([cross.right_top(), cross.left_bottom()], stroke);
+                }
+            }
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use egui::{Context, Id};
+
+    use super::*;
+
+    #[test]
+    fn batched_meters_are_one_mesh() {
+        let ctx = Context::default();
+        let run = || {
+            ctx.run(Default::default(), |ctx| {
+                egui::Area::new(Id::new("bridge")).show(ctx, |ui| {
+                    ui.add(MeterBridge::new(&[-12.0, -30.0, -6.0, -100.0]).batched(true));
+                });
+            })
+        };
+        // Areas are invisible on their first frame while they are sized.
+        run();
+        let output = run();
+        assert_eq!(output.shapes.len(), 1);
+        assert!(matches!(output.shapes[0].shape, Shape::Mesh(..)));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{FaderPeak, PeakDetector, SignalKind};
+
+/// The held peaks and clip light of a [`crate::Fader`]'s meter.
+///
+/// Faders keep this in egui memory by default, touching it once a frame. Give a fader its own
+/// with [`crate::Fader::meter_state`] to keep it with the rest of a channel's state, and the
+/// fader leaves memory alone for its meter.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_level: f32 = -10.0;
+/// # let mut my_meter = egui_fader::MeterState::default();
+/// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).meter_state(&mut my_meter));
+/// if ui.button("Clear clips").clicked() {
+///     my_meter.reset();
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MeterState {
+    peak: Option<FaderPeak>,
+    clip_lit: bool,
+    clipping: bool,
+    /// The signal then peak positions of each channel last drawn, for
+    /// [`crate::Fader::meter_repaint`].
+    pub(crate) shown: Vec<f32>,
+    #[cfg(feature = "accesskit")]
+    pub(crate) announcement: Option<String>,
+}
+
+impl MeterState {
+    /// The held peak of each channel as shown on the meter, one value for a mono fader and left
+    /// and right for a stereo fader. `None` until the fader has been shown.
+    pub fn peaks(&self) -> Option<Vec<f32>> {
+        self.peak
+            .as_ref()
+            .map(|peak| peak.current().channels().to_vec())
+    }
+
+    /// Whether the clip light is lit, see [`crate::Fader::clip_indicator`].
+    pub fn clip_lit(&self) -> bool {
+        self.clip_lit
+    }
+
+    /// Clear the held peaks and the clip light.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Add this frame's signal to the peak buffers and return the held peaks.
+    pub(crate) fn next_peak(&mut self, signal: SignalKind, buffer_size: usize) -> SignalKind {
+        let new_peak = || match signal {
+            SignalKind::Mono(..) => FaderPeak::Mono(PeakDetector::new(buffer_size)),
+            SignalKind::Stereo(..) => FaderPeak::Stereo([
+                PeakDetector::new(buffer_size),
+                PeakDetector::new(buffer_size),
+            ]),
+        };
+        let peak = self.peak.get_or_insert_with(new_peak);
+        // Start over when the buffer size changes, e.g. when reduced motion is turned on, or the
+        // state is moved between a mono and a stereo fader.
+        if peak.buffer_size() != buffer_size.max(1) || !peak.fits(signal) {
+            *peak = new_peak();
+        }
+        peak.next(signal)
+    }
+
+    /// Latch the clip light while `clipped`, or clear it when `cleared`.
+    /// Returns whether the signal has just started clipping.
+    pub(crate) fn update_clip(&mut self, clipped: bool, cleared: bool) -> bool {
+        if cleared {
+            self.clip_lit = false;
+        } else if clipped {
+            self.clip_lit = true;
+        }
+        let started = clipped && !self.clipping;
+        self.clipping = clipped;
+        started
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clip_light_latches_until_cleared() {
+        let mut state = MeterState::default();
+        assert!(state.update_clip(true, false));
+        assert!(!state.update_clip(false, false));
+        assert!(state.clip_lit());
+        state.update_clip(false, true);
+        assert!(!state.clip_lit());
+        state.next_peak(SignalKind::Mono(-6.0), 4);
+        assert_eq!(state.peaks(), Some(vec![-6.0]));
+        state.reset();
+        assert_eq!(state.peaks(), None);
+    }
+}
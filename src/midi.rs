@@ -0,0 +1,68 @@
+//! Conversions between fader levels and MIDI control change values.
+//!
+//! These use the same piecewise mapping as [`crate::Fader`], so a hardware controller and the
+//! on-screen fader follow identical curves.
+
+use crate::{normalised_from_value, value_from_normalised};
+
+const CC7_MAX: f32 = 127.0;
+const CC14_MAX: f32 = 16383.0;
+
+/// Convert a level into a 7-bit MIDI CC value (0–127).
+/// `weights` are the fader's segment weights, or `None` for evenly spaced increments.
+pub fn cc7_from_level(level: f32, increments: &[f32], weights: Option<&[f32]>) -> u8 {
+    let normalised = normalised_from_value(level, increments, weights);
+    (normalised * CC7_MAX).round() as u8
+}
+
+/// Convert a 7-bit MIDI CC value (0–127) into a level.
+/// Values above 127 are clamped.
+pub fn level_from_cc7(cc: u8, increments: &[f32], weights: Option<&[f32]>) -> f32 {
+    let normalised = normalised_from_cc7(cc);
+    value_from_normalised(normalised, increments, weights)
+}
+
+/// Convert a level into a 14-bit MIDI CC value (0–16383).
+/// `weights` are the fader's segment weights, or `None` for evenly spaced increments.
+pub fn cc14_from_level(level: f32, increments: &[f32], weights: Option<&[f32]>) -> u16 {
+    let normalised = normalised_from_value(level, increments, weights);
+    (normalised * CC14_MAX).round() as u16
+}
+
+/// Convert a 14-bit MIDI CC value (0–16383) into a level.
+/// Values above 16383 are clamped.
+pub fn level_from_cc14(cc: u16, increments: &[f32], weights: Option<&[f32]>) -> f32 {
+    let normalised = normalised_from_cc14(cc);
+    value_from_normalised(normalised, increments, weights)
+}
+
+pub(crate) fn normalised_from_cc7(cc: u8) -> f32 {
+    (cc as f32 / CC7_MAX).min(1.0)
+}
+
+pub(crate) fn normalised_from_cc14(cc: u16) -> f32 {
+    (cc as f32 / CC14_MAX).min(1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INCREMENTS: [f32; 5] = [-100.0, -30.0, -10.0, 0.0, 10.0];
+
+    #[test]
+    fn cc7_extremes_map_to_range_ends() {
+        assert_eq!(level_from_cc7(0, &INCREMENTS, None), f32::NEG_INFINITY);
+        assert_eq!(level_from_cc7(127, &INCREMENTS, None), 10.0);
+        assert_eq!(cc7_from_level(f32::NEG_INFINITY, &INCREMENTS, None), 0);
+        assert_eq!(cc7_from_level(10.0, &INCREMENTS, None), 127);
+    }
+
+    #[test]
+    fn cc14_round_trips_increments() {
+        for level in &INCREMENTS[1..] {
+            let cc = cc14_from_level(*level, &INCREMENTS, None);
+            assert!((level_from_cc14(cc, &INCREMENTS, None) - level).abs() < 0.01);
+        }
+    }
+}
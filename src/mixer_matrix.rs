@@ -0,0 +1,307 @@
+use std::borrow::Cow;
+
+use egui::{
+    Align2, FontId, Modifiers, Rect, Response, Sense, Ui, Widget, epaint, lerp, pos2, vec2,
+};
+
+use crate::knob::drag_normalised;
+use crate::{
+    DEFAULT_INCREMENTS, FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, INFINITY,
+    any_modifier_held, normalised_from_value, value_from_normalised,
+};
+
+/// One cell of a [`MixerMatrix`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatrixSend {
+    /// The send level, kept while the send is switched off.
+    pub level: f32,
+
+    /// Whether the send is switched on.
+    pub enabled: bool,
+}
+
+impl Default for MatrixSend {
+    fn default() -> Self {
+        Self {
+            level: 0.0,
+            enabled: false,
+        }
+    }
+}
+
+impl MatrixSend {
+    /// The level actually sent, `NEG_INFINITY` while switched off.
+    pub fn effective_level(&self) -> f32 {
+        if self.enabled { self.level } else { -INFINITY }
+    }
+}
+
+/// A routing grid of small send level cells, e.g. inputs as rows and buses as columns.
+///
+/// Drag a cell up or down to change its level with the same taper and fine drag as a
+/// [`crate::Fader`], double click to return it to 0 dB and right click to switch it on or off.
+/// Sends are stored row by row.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_sends = [egui_fader::MatrixSend::default(); 6];
+/// ui.add(
+///     egui_fader::MixerMatrix::new(&mut my_sends, 3)
+///         .row_labels(&["Vox", "Gtr"])
+///         .column_labels(&["A", "B", "C"]),
+/// );
+/// # });
+/// ```
+pub struct MixerMatrix<'a> {
+    sends: &'a mut [MatrixSend],
+    columns: usize,
+    row_labels: &'a [&'a str],
+    column_labels: &'a [&'a str],
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    cell_size: Option<f32>,
+    drag_distance: f32,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> MixerMatrix<'a> {
+    /// Creates a matrix of `sends` laid out in rows of `columns` cells.
+    pub fn new(sends: &'a mut [MatrixSend], columns: usize) -> Self {
+        Self {
+            sends,
+            columns: columns.max(1),
+            row_labels: &[],
+            column_labels: &[],
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            cell_size: None,
+            drag_distance: 100.0,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the labels to the left of the rows.
+    #[inline]
+    pub fn row_labels(mut self, row_labels: &'a [&'a str]) -> Self {
+        self.row_labels = row_labels;
+        self
+    }
+
+    /// Set the labels above the columns.
+    #[inline]
+    pub fn column_labels(mut self, column_labels: &'a [&'a str]) -> Self {
+        self.column_labels = column_labels;
+        self
+    }
+
+    /// Set the increments of the send levels, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set a relative weight for each segment between increments, see
+    /// [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the width and height of each cell in points.
+    /// The default is 1.5 times `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = Some(cell_size);
+        self
+    }
+
+    /// Set how far the pointer must be dragged to go through the whole range.
+    /// Default: `100.0` points.
+    #[inline]
+    pub fn drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Set the size of the row and column labels.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the cells. The `channel` colour is the background of a cell,
+    /// `rail_fill` the level and `label_text` the labels.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, level: f32) -> f32 {
+        normalised_from_value(level, &self.increments, self.segment_weights.as_deref())
+    }
+
+    fn denormalise(&self, normalised: f32) -> f32 {
+        value_from_normalised(
+            normalised,
+            &self.increments,
+            self.segment_weights.as_deref(),
+        )
+    }
+
+    fn cell_interaction(&mut self, ui: &Ui, response: &Response, index: usize) {
+        if response.secondary_clicked() {
+            self.sends[index].enabled = !self.sends[index].enabled;
+        }
+        if response.double_clicked() {
+            let min = self.increments[0];
+            let max = self.increments[self.increments.len() - 1];
+            self.sends[index].level = 0.0_f32.clamp(min, max);
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        };
+        let delta = -response.drag_delta().y * ratio / self.drag_distance;
+        let normalised = self.normalise(self.sends[index].level);
+        if let Some(normalised) = drag_normalised(ui, response, normalised, delta) {
+            self.sends[index].level = self.denormalise(normalised);
+        }
+    }
+
+    fn cell_ui(&self, ui: &Ui, response: &Response, send: MatrixSend) {
+        let visuals = ui.style().interact(response);
+        let rect = response.rect.shrink(1.0);
+        let background = self.style.channel.unwrap_or(ui.visuals().faint_bg_color);
+        ui.painter()
+            .rect_filled(rect, visuals.corner_radius, background);
+        let fill_colour = self
+            .style
+            .rail_fill
+            .unwrap_or(ui.visuals().selection.bg_fill);
+        let fill_colour = if send.enabled {
+            fill_colour
+        } else {
+            fill_colour.gamma_multiply(0.25)
+        };
+        let top = lerp(rect.bottom()..=rect.top(), self.normalise(send.level));
+        let fill = Rect::from_x_y_ranges(rect.x_range(), top..=rect.bottom());
+        ui.painter()
+            .rect_filled(fill, visuals.corner_radius, fill_colour);
+        ui.painter().rect_stroke(
+            rect,
+            visuals.corner_radius,
+            visuals.bg_stroke,
+            epaint::StrokeKind::Inside,
+        );
+    }
+}
+
+impl Widget for MixerMatrix<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let cell = self
+            .cell_size
+            .unwrap_or_else(|| 1.5 * ui.spacing().interact_size.y);
+        let rows = self.sends.len().div_ceil(self.columns);
+        let label_width = if self.row_labels.is_empty() {
+            0.0
+        } else {
+            4.0 * self.text_size
+        };
+        let label_height = if self.column_labels.is_empty() {
+            0.0
+        } else {
+            self.text_size * 1.25
+        };
+        let size = vec2(
+            label_width + self.columns as f32 * cell,
+            label_height + rows as f32 * cell,
+        );
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::hover());
+        let grid_min = rect.min + vec2(label_width, label_height);
+        let text_colour = self.style.label_text.unwrap_or(ui.visuals().text_color());
+        let font_id = FontId::proportional(self.text_size);
+        for index in 0..self.sends.len() {
+            let (row, column) = (index / self.columns, index % self.columns);
+            let cell_rect = Rect::from_min_size(
+                grid_min + vec2(column as f32 * cell, row as f32 * cell),
+                vec2(cell, cell),
+            );
+            let cell_response =
+                ui.interact(cell_rect, response.id.with(index), Sense::click_and_drag());
+            let old_send = self.sends[index];
+            self.cell_interaction(ui, &cell_response, index);
+            if self.sends[index] != old_send {
+                response.mark_changed();
+            }
+            if ui.is_rect_visible(cell_rect) {
+                self.cell_ui(ui, &cell_response, self.sends[index]);
+            }
+        }
+        if ui.is_rect_visible(rect) {
+            for (row, label) in self.row_labels.iter().enumerate().take(rows) {
+                let y = grid_min.y + (row as f32 + 0.5) * cell;
+                ui.painter().text(
+                    pos2(rect.left(), y),
+                    Align2::LEFT_CENTER,
+                    label,
+                    font_id.clone(),
+                    text_colour,
+                );
+            }
+            for (column, label) in self.column_labels.iter().enumerate().take(self.columns) {
+                let x = grid_min.x + (column as f32 + 0.5) * cell;
+                ui.painter().text(
+                    pos2(x, rect.top()),
+                    Align2::CENTER_TOP,
+                    label,
+                    font_id.clone(),
+                    text_colour,
+                );
+            }
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn switched_off_sends_are_silent() {
+        let mut send = MatrixSend {
+            level: -6.0,
+            enabled: true,
+        };
+        assert_eq!(send.effective_level(), -6.0);
+        send.enabled = false;
+        assert_eq!(send.effective_level(), -INFINITY);
+    }
+}
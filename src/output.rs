@@ -0,0 +1,32 @@
+use egui::Response;
+
+use crate::FaderRects;
+
+/// Everything a [`crate::Fader`] knows after it is shown, returned by [`crate::Fader::show`].
+#[derive(Clone, Debug)]
+pub struct FaderOutput {
+    /// The response of the whole fader, the same as adding it with [`egui::Ui::add`].
+    pub response: Response,
+
+    /// The areas of the fader, e.g. to line up another meter with its own.
+    pub rects: FaderRects,
+
+    /// The held peak of each channel as shown on the meter: one value for a mono fader, left and
+    /// right for a stereo fader. Empty if the meter wasn't shown.
+    pub peaks: Vec<f32>,
+
+    /// Whether the signal of any channel was above the [`crate::Fader::overload_level`] this
+    /// frame.
+    pub clipped: bool,
+
+    /// Whether a drag gesture started this frame.
+    pub drag_started: bool,
+
+    /// Whether a drag gesture ended this frame.
+    pub drag_stopped: bool,
+
+    /// The level when the current drag gesture started, set while dragging and on the frame the
+    /// drag ends. Push one undo entry from this level when [`Self::drag_stopped`] is true rather
+    /// than one for every frame of movement.
+    pub drag_start_level: Option<f64>,
+}
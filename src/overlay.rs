@@ -0,0 +1,41 @@
+use egui::{Rangef, Rect, lerp};
+
+use crate::normalised_from_value;
+
+/// Areas of a [`crate::Fader`], passed to the [`crate::Fader::overlay`] painter.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FaderRects {
+    /// The column holding the rail and handle.
+    pub rail: Rect,
+
+    /// The column holding the increment labels, `Rect::NOTHING` when they are hidden.
+    pub labels: Rect,
+
+    /// The column holding the signal meter.
+    pub meter: Rect,
+
+    /// The row underneath the fader holding the level readout.
+    pub readout: Rect,
+}
+
+/// State of a [`crate::Fader`], passed to the [`crate::Fader::overlay`] painter.
+#[derive(Clone, Debug)]
+pub struct FaderState<'s> {
+    /// The current level.
+    pub level: f64,
+
+    /// Whether the handle is being dragged.
+    pub dragged: bool,
+
+    pub(crate) position_range: Rangef,
+    pub(crate) increments: &'s [f32],
+    pub(crate) weights: Option<&'s [f32]>,
+}
+
+impl FaderState<'_> {
+    /// The vertical position of a level on the rail, in line with the increment labels.
+    pub fn y_from_level(&self, level: f32) -> f32 {
+        let normalised = normalised_from_value(level, self.increments, self.weights);
+        lerp(self.position_range, normalised)
+    }
+}
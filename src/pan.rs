@@ -0,0 +1,222 @@
+use egui::{Align2, FontId, Modifiers, Response, Sense, Ui, Widget, pos2, vec2};
+
+use crate::knob::{drag_normalised, knob_body_ui};
+use crate::{FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, any_modifier_held};
+
+/// The readout shows the pan in steps from `C` to `L64` and `R64`.
+const PAN_READOUT_STEPS: f32 = 64.0;
+/// Pans within this distance of the centre snap to it while dragging.
+const PAN_DETENT: f32 = 0.04;
+
+/// A pan control from `-1.0` (left) to `1.0` (right), drawn as a knob to match the
+/// [`crate::Fader`].
+///
+/// The readout shows the position as `L64` to `C` to `R64`. Dragging snaps to the centre when
+/// close to it and double clicking returns to the centre.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_pan: f32 = 0.0;
+/// ui.add(egui_fader::Pan::new(&mut my_pan));
+/// # });
+/// ```
+pub struct Pan<'a> {
+    pan: &'a mut f32,
+    detent: bool,
+    diameter: Option<f32>,
+    drag_distance: f32,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    show_value: bool,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> Pan<'a> {
+    /// Creates a pan control, values are clamped to `-1.0..=1.0`.
+    pub fn new(pan: &'a mut f32) -> Self {
+        Self {
+            pan,
+            detent: true,
+            diameter: None,
+            drag_distance: 200.0,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            show_value: true,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set whether dragging snaps to the centre when close to it. Default: `true`.
+    #[inline]
+    pub fn detent(mut self, detent: bool) -> Self {
+        self.detent = detent;
+        self
+    }
+
+    /// Set the diameter of the knob in points.
+    /// The default is twice `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Set how far the pointer must be dragged to pan from left to right.
+    /// Default: `200.0` points.
+    #[inline]
+    pub fn drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Show the pan underneath the knob. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the knob, see [`crate::Knob::style`].
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn interaction(&mut self, ui: &Ui, response: &Response) {
+        if response.double_clicked() {
+            *self.pan = 0.0;
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let speed = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        } / self.drag_distance;
+        let normalised = 0.5 * (*self.pan + 1.0);
+        if let Some(normalised) =
+            drag_normalised(ui, response, normalised, -response.drag_delta().y * speed)
+        {
+            *self.pan = snap_to_centre(2.0 * normalised - 1.0, self.detent);
+        }
+    }
+}
+
+impl Widget for Pan<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_pan = *self.pan;
+        // An out of range pan is written back clamped, and reported as a change.
+        *self.pan = self.pan.clamp(-1.0, 1.0);
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let text_height = if self.show_value {
+            self.text_size * 1.25
+        } else {
+            0.0
+        };
+        let size = vec2(diameter, diameter + text_height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        self.interaction(ui, &response);
+        if ui.is_rect_visible(rect) {
+            let radius = 0.5 * diameter;
+            let center = pos2(rect.center().x, rect.top() + radius);
+            let normalised = 0.5 * (*self.pan + 1.0);
+            knob_body_ui(ui, &response, &self.style, center, radius, 0.5, normalised);
+            if self.show_value {
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    rect.center_bottom(),
+                    Align2::CENTER_BOTTOM,
+                    pan_text(*self.pan),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        if *self.pan != old_pan {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+fn snap_to_centre(pan: f32, detent: bool) -> f32 {
+    if detent && pan.abs() < PAN_DETENT {
+        0.0
+    } else {
+        pan
+    }
+}
+
+/// Text for a pan position, e.g. `L32`, `C` or `R64`.
+fn pan_text(pan: f32) -> String {
+    let steps = (pan * PAN_READOUT_STEPS).round();
+    if steps < 0.0 {
+        format!("L{}", -steps)
+    } else if steps > 0.0 {
+        format!("R{steps}")
+    } else {
+        "C".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pan_text_counts_steps_from_centre() {
+        assert_eq!(pan_text(-1.0), "L64");
+        assert_eq!(pan_text(0.5), "R32");
+        assert_eq!(pan_text(0.001), "C");
+        assert_eq!(pan_text(-0.001), "C");
+    }
+
+    #[test]
+    fn pan_snaps_to_centre_with_detent() {
+        assert_eq!(snap_to_centre(0.02, true), 0.0);
+        assert_eq!(snap_to_centre(0.02, false), 0.02);
+        assert_eq!(snap_to_centre(-0.5, true), -0.5);
+    }
+
+    #[test]
+    fn out_of_range_pan_is_clamped_as_a_change() {
+        let ctx = egui::Context::default();
+        let mut pan = 1.5;
+        let mut changed = Vec::new();
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    changed.push(ui.add(Pan::new(&mut pan)).changed());
+                });
+            });
+        }
+        assert_eq!(pan, 1.0);
+        assert_eq!(changed, [true, false]);
+    }
+}
@@ -36,6 +36,8 @@ impl Default for BufferElement {
 ///
 /// This keeps the deque sorted and set to only the buffer giving
 /// efficiently returning of the max value.
+/// Each value is pushed and popped at most once, so [`PeakDetector::next`] is O(1) amortised
+/// whatever the buffer size.
 #[derive(Clone, Debug)]
 pub struct PeakDetector {
     deque: VecDeque<BufferElement>,
@@ -44,20 +46,26 @@ pub struct PeakDetector {
 }
 
 impl PeakDetector {
+    /// A buffer size of 0 is treated as 1, i.e. no hold.
     pub fn new(buffer_size: usize) -> Self {
         Self {
-            buffer_size,
+            buffer_size: buffer_size.max(1),
             deque: VecDeque::default(),
             next_index: 0,
         }
     }
 
     /// Add new element to buffer and return highest value.
+    /// NaN is held as `NEG_INFINITY`, otherwise it would sort above every other value.
     pub fn next(&mut self, value: f32) -> f32 {
         let deque = &mut self.deque;
         let buffer_size = self.buffer_size;
         let next_index = self.next_index;
-        let value = OrderedFloat(value);
+        let value = OrderedFloat(if value.is_nan() {
+            f32::NEG_INFINITY
+        } else {
+            value
+        });
         // Remove values no longer in the buffer.
         // An element will only stay in the buffer long enough to require removal if its value is
         // the max value.
@@ -97,7 +105,11 @@ impl PeakDetector {
         deque.back().unwrap().value.into_inner()
     }
 
-    #[allow(dead_code)]
+    /// The number of values the maximum is taken over.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
     /// Get current max value in buffer.
     pub fn current(&self) -> f32 {
         self.deque
@@ -161,6 +173,39 @@ mod test {
         assert_eq!(detector.current(), expected);
     }
 
+    #[test]
+    fn matches_sliding_window_max() {
+        let buffer_size = 7;
+        let mut detector = PeakDetector::new(buffer_size);
+        // A repeatable jumble of values, including runs of equal values.
+        let values: Vec<f32> = (0..200).map(|it| ((it * 37 % 23) / 3) as f32).collect();
+        for (index, &value) in values.iter().enumerate() {
+            let window = &values[(index + 1).saturating_sub(buffer_size)..=index];
+            let expected = window.iter().copied().fold(f32::MIN, f32::max);
+            assert_eq!(detector.next(value), expected);
+        }
+    }
+
+    #[test]
+    fn zero_buffer_size_holds_nothing() {
+        let mut detector = PeakDetector::new(0);
+        assert_eq!(detector.next(0.5), 0.5);
+        assert_eq!(detector.next(0.1), 0.1);
+    }
+
+    #[test]
+    fn nan_is_never_held() {
+        let mut detector = PeakDetector::new(4);
+        assert_eq!(detector.next(0.3), 0.3);
+        assert_eq!(detector.next(f32::NAN), 0.3);
+        for _ in 0..4 {
+            detector.next(0.1);
+        }
+        assert_eq!(detector.current(), 0.1);
+        let mut detector = PeakDetector::new(4);
+        assert_eq!(detector.next(f32::NAN), f32::NEG_INFINITY);
+    }
+
     #[test]
     fn empty_buffer_returns_0() {
         let detector = PeakDetector::new(10);
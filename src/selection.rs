@@ -0,0 +1,114 @@
+use std::collections::BTreeSet;
+
+use crate::{normalised_from_value, value_from_normalised};
+
+/// A set of selected faders that are dragged together.
+///
+/// Pass the selection to each fader with [`crate::Fader::selection`] and command click (control
+/// on Windows and Linux) a fader to select it. Shift dragging a selected fader records how far it
+/// moved along its travel, then [`FaderSelection::apply`] moves the other selected levels by the
+/// same distance, each stopping at the ends of its own travel. The modifier is set with
+/// [`crate::Fader::group_drag_modifiers`]. A [`crate::FaderBank`] applies its selection itself.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_selection = egui_fader::FaderSelection::default();
+/// # let mut my_levels = [0.0_f32, -6.0, -10.0, -20.0];
+/// ui.horizontal(|ui| {
+///     for (index, level) in my_levels.iter_mut().enumerate() {
+///         ui.add(egui_fader::Fader::mono(level, -20.0).selection(&mut my_selection, index));
+///     }
+/// });
+/// my_selection.apply(&mut my_levels, &[-100.0, -30.0, -10.0, 0.0, 10.0], None);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaderSelection {
+    selected: BTreeSet<usize>,
+    /// The dragged fader and how far it moved this frame, as a fraction of its travel.
+    drag: Option<(usize, f32)>,
+}
+
+impl FaderSelection {
+    /// Whether the fader at `index` is selected.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// Select or deselect the fader at `index`.
+    pub fn set_selected(&mut self, index: usize, selected: bool) {
+        if selected {
+            self.selected.insert(index);
+        } else {
+            self.selected.remove(&index);
+        }
+    }
+
+    /// Select the fader at `index` if it isn't selected, otherwise deselect it.
+    pub fn toggle(&mut self, index: usize) {
+        let selected = self.is_selected(index);
+        self.set_selected(index, !selected);
+    }
+
+    /// Deselect every fader.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+    }
+
+    /// The indices of the selected faders in ascending order.
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// Record a drag of the fader at `index`, only selected faders move the others.
+    pub(crate) fn dragged(&mut self, index: usize, normalised_delta: f32) {
+        if self.is_selected(index) && normalised_delta != 0.0 {
+            self.drag = Some((index, normalised_delta));
+        }
+    }
+
+    /// Move the other selected `levels` with the dragged fader, call this once per frame after
+    /// the faders are shown. The increments and weights are those of the faders, see
+    /// [`crate::Fader::increments`].
+    pub fn apply(
+        &mut self,
+        levels: &mut [f32],
+        increments: &[f32],
+        segment_weights: Option<&[f32]>,
+    ) {
+        let Some((dragged, delta)) = self.drag.take() else {
+            return;
+        };
+        for &index in &self.selected {
+            if index == dragged {
+                continue;
+            }
+            if let Some(level) = levels.get_mut(index) {
+                let normalised = normalised_from_value(*level, increments, segment_weights);
+                let normalised = (normalised + delta).clamp(0.0, 1.0);
+                *level = value_from_normalised(normalised, increments, segment_weights);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn selected_levels_follow_and_clamp() {
+        let increments = [-100.0, -30.0, -10.0, 0.0, 10.0];
+        let mut selection = FaderSelection::default();
+        for index in [0, 1, 3] {
+            selection.set_selected(index, true);
+        }
+        let mut levels = [0.0, -30.0, -10.0, 10.0];
+        selection.dragged(0, 0.25);
+        selection.apply(&mut levels, &increments, None);
+        assert_eq!(levels, [0.0, -10.0, -10.0, 10.0]);
+        selection.dragged(2, -0.25);
+        selection.apply(&mut levels, &increments, None);
+        assert_eq!(levels, [0.0, -10.0, -10.0, 10.0]);
+    }
+}
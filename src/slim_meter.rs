@@ -0,0 +1,221 @@
+use std::borrow::Cow;
+
+use egui::{Align2, FontId, Rangef, Rect, Response, Sense, Ui, Widget, lerp, pos2, vec2};
+
+use crate::peak::PeakDetector;
+use crate::{DEFAULT_INCREMENTS, FaderStyle, normalised_from_value};
+
+/// A thin horizontal meter with a held peak, for track headers and list rows.
+///
+/// The bar is only a few points tall and grows to the right using the same scale mapping as a
+/// [`crate::Fader`]. The held peak can optionally be shown as text after the bar.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let my_signal = -12.0;
+/// ui.horizontal(|ui| {
+///     ui.label("Vocals");
+///     ui.add(egui_fader::SlimMeter::new(my_signal).show_readout(true));
+/// });
+/// # });
+/// ```
+pub struct SlimMeter<'a> {
+    signal: f32,
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    width: Option<f32>,
+    thickness: f32,
+    peak_buffer_size: usize,
+    show_readout: bool,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> SlimMeter<'a> {
+    /// Creates a slim meter showing `signal`.
+    pub fn new(signal: f32) -> Self {
+        Self {
+            signal,
+            increments: Cow::Borrowed(&DEFAULT_INCREMENTS),
+            segment_weights: None,
+            width: None,
+            thickness: 3.0,
+            peak_buffer_size: 60,
+            show_readout: false,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the meter scale, see [`crate::Fader::increments`].
+    #[inline]
+    pub fn increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.increments = increments.into();
+        self
+    }
+
+    /// Set a relative weight for each segment between increments, see
+    /// [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the width of the bar in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height of the bar in points. Default: `3.0`.
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set the number of frames that will be stored in the peak buffer.
+    #[inline]
+    pub fn peak_buffer_size(mut self, peak_buffer_size: usize) -> Self {
+        self.peak_buffer_size = peak_buffer_size;
+        self
+    }
+
+    /// Show the held peak as text after the bar. Default: `false`.
+    #[inline]
+    pub fn show_readout(mut self, show_readout: bool) -> Self {
+        self.show_readout = show_readout;
+        self
+    }
+
+    /// Set the size of the readout text.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the meter. The `channel`, `signal`, `signal_gradient`, `peak` and
+    /// `readout_text` colours are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        normalised_from_value(value, &self.increments, self.segment_weights.as_deref())
+    }
+
+    fn next_peak(&self, ui: &Ui, response: &Response) -> f32 {
+        let id = response.id.with("peak");
+        ui.memory_mut(|mem| {
+            mem.data
+                .get_temp_mut_or_insert_with(id, || PeakDetector::new(self.peak_buffer_size))
+                .next(self.signal)
+        })
+    }
+
+    fn meter_ui(&self, ui: &Ui, rect: Rect, peak: f32) {
+        let visuals = ui.visuals();
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(visuals.widgets.inactive.corner_radius);
+        let channel_colour = self.style.channel.unwrap_or(visuals.faint_bg_color);
+        ui.painter().rect_filled(rect, corner, channel_colour);
+
+        let signal_x = lerp(rect.x_range(), self.normalise(self.signal));
+        let signal_rect = Rect::from_x_y_ranges(rect.left()..=signal_x, rect.y_range());
+        match &self.style.signal_gradient {
+            None => {
+                let colour = self
+                    .style
+                    .signal
+                    .unwrap_or(visuals.widgets.active.fg_stroke.color);
+                ui.painter().rect_filled(signal_rect, corner, colour);
+            }
+            Some(gradient) => {
+                for (start, end, colour) in gradient.zones(|level| self.normalise(level)) {
+                    let zone_x =
+                        Rangef::new(lerp(rect.x_range(), start), lerp(rect.x_range(), end));
+                    let zone_rect =
+                        signal_rect.intersect(Rect::from_x_y_ranges(zone_x, rect.y_range()));
+                    if zone_rect.is_positive() {
+                        ui.painter().rect_filled(zone_rect, corner, colour);
+                    }
+                }
+            }
+        }
+
+        let peak_colour = self
+            .style
+            .peak
+            .unwrap_or(visuals.widgets.inactive.fg_stroke.color);
+        let peak_x = lerp(rect.x_range(), self.normalise(peak));
+        let peak_x = peak_x.clamp(rect.left() + 1.0, rect.right() - 1.0);
+        let peak_rect =
+            Rect::from_center_size(pos2(peak_x, rect.center().y), vec2(2.0, rect.height()));
+        ui.painter().rect_filled(peak_rect, 0.0, peak_colour);
+    }
+}
+
+impl Widget for SlimMeter<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let width = self.width.unwrap_or_else(|| ui.spacing().slider_width);
+        // Room for e.g. "-100.0".
+        let readout_width = if self.show_readout {
+            3.0 * self.text_size
+        } else {
+            0.0
+        };
+        let height = if self.show_readout {
+            self.thickness.max(self.text_size)
+        } else {
+            self.thickness
+        };
+        let size = vec2(width + readout_width, height);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let peak = self.next_peak(ui, &response);
+        if ui.is_rect_visible(rect) {
+            let bar_rect = Rect::from_center_size(
+                pos2(rect.left() + 0.5 * width, rect.center().y),
+                vec2(width, self.thickness),
+            );
+            self.meter_ui(ui, bar_rect, peak);
+            if self.show_readout {
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    rect.right_center(),
+                    Align2::RIGHT_CENTER,
+                    peak_text(peak),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        response
+    }
+}
+
+fn peak_text(peak: f32) -> String {
+    if peak == f32::NEG_INFINITY {
+        "-∞".to_owned()
+    } else {
+        format!("{peak:.1}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn peak_text_shows_infinity() {
+        assert_eq!(peak_text(f32::NEG_INFINITY), "-∞");
+        assert_eq!(peak_text(-6.04), "-6.0");
+    }
+}
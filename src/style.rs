@@ -0,0 +1,211 @@
+use egui::{Color32, CornerRadius, Stroke};
+
+/// Colours and corner radii of a [`crate::Fader`].
+///
+/// Everything is optional, anything left as `None` is derived from the [`egui::Visuals`] of
+/// the `Ui` the fader is added to. This allows applying brand colours to individual faders
+/// without changing the theme of the whole app.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_level: f32 = -10.0;
+/// let style = egui_fader::FaderStyle {
+///     signal: Some(egui::Color32::from_rgb(0, 200, 120)),
+///     ..Default::default()
+/// };
+/// ui.add(egui_fader::Fader::mono(&mut my_level, -20.0).style(style));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FaderStyle {
+    /// The rail the handle moves along.
+    pub rail: Option<Color32>,
+
+    /// The filled part of the rail on a bipolar fader.
+    pub rail_fill: Option<Color32>,
+
+    /// The inside of the handle.
+    pub handle_fill: Option<Color32>,
+
+    /// The outline of the handle.
+    pub handle_stroke: Option<Stroke>,
+
+    /// The background of the signal meter channels.
+    pub channel: Option<Color32>,
+
+    /// The major tick marks beside the rail, minor ticks are drawn thinner and fainter.
+    pub tick: Option<Stroke>,
+
+    /// The neutral and reference lines across the rail.
+    pub reference_line: Option<Stroke>,
+
+    /// The shaded part of the meter channels above the overload level.
+    pub overload: Option<Color32>,
+
+    /// The signal bar.
+    pub signal: Option<Color32>,
+
+    /// Colour the signal bar by level, this takes priority over `signal`.
+    pub signal_gradient: Option<MeterGradient>,
+
+    /// The peak marker.
+    pub peak: Option<Color32>,
+
+    /// The clip light of a [`crate::Fader`] while lit.
+    pub clip: Option<Color32>,
+
+    /// The level readout underneath the rail.
+    pub readout_text: Option<Color32>,
+
+    /// The increment labels and channel labels.
+    pub label_text: Option<Color32>,
+
+    /// The muted badge and a [`crate::MuteButton`] while on.
+    pub mute: Option<Color32>,
+
+    /// A [`crate::SoloButton`] while on.
+    pub solo: Option<Color32>,
+
+    /// Corner radius of the rail and the bipolar fill.
+    pub rail_corner_radius: Option<CornerRadius>,
+
+    /// Corner radius of the meter channels and signal bars.
+    pub meter_corner_radius: Option<CornerRadius>,
+
+    /// Corner radius of the square peak marker.
+    pub peak_corner_radius: Option<CornerRadius>,
+}
+
+impl FaderStyle {
+    /// Black and white with thick outlines and saturated meter colours, for visually impaired
+    /// users and displays in sunlight. See [`crate::Fader::high_contrast`].
+    pub fn high_contrast(dark_mode: bool) -> Self {
+        let (foreground, background) = if dark_mode {
+            (Color32::WHITE, Color32::BLACK)
+        } else {
+            (Color32::BLACK, Color32::WHITE)
+        };
+        let safe = if dark_mode {
+            Color32::from_rgb(0, 255, 0)
+        } else {
+            Color32::from_rgb(0, 150, 0)
+        };
+        let red = Color32::from_rgb(255, 0, 0);
+        Self {
+            rail: Some(Color32::from_gray(128)),
+            rail_fill: Some(Color32::from_rgb(0, 140, 255)),
+            handle_fill: Some(background),
+            handle_stroke: Some(Stroke::new(3.0, foreground)),
+            channel: Some(background),
+            tick: Some(Stroke::new(2.0, foreground)),
+            reference_line: Some(Stroke::new(2.0, foreground)),
+            overload: Some(red.gamma_multiply(0.35)),
+            signal: Some(safe),
+            signal_gradient: Some(MeterGradient {
+                safe,
+                warning: Color32::from_rgb(255, 200, 0),
+                clip: red,
+                ..Default::default()
+            }),
+            peak: Some(foreground),
+            clip: Some(red),
+            readout_text: Some(foreground),
+            label_text: Some(foreground),
+            mute: Some(Color32::from_rgb(255, 200, 0)),
+            solo: Some(Color32::from_rgb(0, 200, 255)),
+            ..Default::default()
+        }
+    }
+
+    /// Fill anything left as `None` from `fallback`.
+    pub(crate) fn or(self, fallback: Self) -> Self {
+        Self {
+            rail: self.rail.or(fallback.rail),
+            rail_fill: self.rail_fill.or(fallback.rail_fill),
+            handle_fill: self.handle_fill.or(fallback.handle_fill),
+            handle_stroke: self.handle_stroke.or(fallback.handle_stroke),
+            channel: self.channel.or(fallback.channel),
+            tick: self.tick.or(fallback.tick),
+            reference_line: self.reference_line.or(fallback.reference_line),
+            overload: self.overload.or(fallback.overload),
+            signal: self.signal.or(fallback.signal),
+            signal_gradient: self.signal_gradient.or(fallback.signal_gradient),
+            peak: self.peak.or(fallback.peak),
+            clip: self.clip.or(fallback.clip),
+            readout_text: self.readout_text.or(fallback.readout_text),
+            label_text: self.label_text.or(fallback.label_text),
+            mute: self.mute.or(fallback.mute),
+            solo: self.solo.or(fallback.solo),
+            rail_corner_radius: self.rail_corner_radius.or(fallback.rail_corner_radius),
+            meter_corner_radius: self.meter_corner_radius.or(fallback.meter_corner_radius),
+            peak_corner_radius: self.peak_corner_radius.or(fallback.peak_corner_radius),
+        }
+    }
+}
+
+/// Colour zones for the signal bar of a [`crate::Fader`], giving at-a-glance headroom.
+///
+/// The bar is coloured `safe` below `warning_level`, `warning` up to `clip_level` and `clip`
+/// above it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct MeterGradient {
+    /// Level where the bar changes from `safe` to `warning`.
+    pub warning_level: f32,
+
+    /// Level where the bar changes from `warning` to `clip`.
+    pub clip_level: f32,
+
+    /// Colour below `warning_level`.
+    pub safe: Color32,
+
+    /// Colour from `warning_level` to `clip_level`.
+    pub warning: Color32,
+
+    /// Colour above `clip_level`.
+    pub clip: Color32,
+}
+
+impl Default for MeterGradient {
+    fn default() -> Self {
+        Self {
+            warning_level: -12.0,
+            clip_level: 0.0,
+            safe: Color32::from_rgb(60, 190, 90),
+            warning: Color32::from_rgb(230, 200, 50),
+            clip: Color32::from_rgb(220, 60, 50),
+        }
+    }
+}
+
+impl MeterGradient {
+    /// The normalised start, end and colour of each zone.
+    pub(crate) fn zones(&self, normalise: impl Fn(f32) -> f32) -> [(f32, f32, Color32); 3] {
+        let warning = normalise(self.warning_level);
+        let clip = normalise(self.clip_level);
+        [
+            (0.0, warning, self.safe),
+            (warning, clip, self.warning),
+            (clip, 1.0, self.clip),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_colours_win_over_the_fallback() {
+        let style = FaderStyle {
+            peak: Some(Color32::RED),
+            ..Default::default()
+        }
+        .or(FaderStyle::high_contrast(true));
+        assert_eq!(style.peak, Some(Color32::RED));
+        assert_eq!(style.channel, Some(Color32::BLACK));
+    }
+}
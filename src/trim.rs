@@ -0,0 +1,340 @@
+use egui::{
+    Align2, FontId, Modifiers, Rangef, Rect, Response, Sense, Ui, Vec2, Widget, epaint, lerp, pos2,
+    vec2,
+};
+
+use crate::knob::drag_normalised;
+use crate::{FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, any_modifier_held};
+
+/// Trims within this fraction of the travel from 0 snap to it while dragging.
+const TRIM_DETENT: f32 = 0.03;
+
+/// A compact horizontal fader for input trim or gain, centred on 0 dB.
+///
+/// It has a short travel, a small handle and no meter, so it fits above the [`crate::Fader`] of
+/// a channel strip. Dragging snaps to 0 when close to it and double clicking returns to 0.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_trim: f32 = 0.0;
+/// ui.add(egui_fader::Trim::new(&mut my_trim).range(18.0));
+/// # });
+/// ```
+pub struct Trim<'a> {
+    trim: &'a mut f32,
+    range: f32,
+    detent: bool,
+    width: Option<f32>,
+    handle_size: Option<f32>,
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    show_value: bool,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> Trim<'a> {
+    /// Creates a trim control, values are clamped to `-range..=range`.
+    pub fn new(trim: &'a mut f32) -> Self {
+        Self {
+            trim,
+            range: 12.0,
+            detent: true,
+            width: None,
+            handle_size: None,
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            show_value: true,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set how far the trim goes either side of 0. Default: `12.0` dB.
+    #[inline]
+    pub fn range(mut self, range: f32) -> Self {
+        self.range = range.abs();
+        self
+    }
+
+    /// Set whether dragging snaps to 0 when close to it. Default: `true`.
+    #[inline]
+    pub fn detent(mut self, detent: bool) -> Self {
+        self.detent = detent;
+        self
+    }
+
+    /// Set the width of the trim in points.
+    /// The default is 1.5 times `egui::Ui.spacing().interact_size.x`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height of the handle in points, its width is half the height.
+    /// The default is 0.75 times `egui::Ui.spacing().interact_size.y`.
+    #[inline]
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = Some(handle_size);
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Show the trim underneath the rail. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the trim. The `rail`, `rail_fill`, `handle_fill`, `handle_stroke` and
+    /// `readout_text` colours are used.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalised(&self) -> f32 {
+        normalised_from_trim(*self.trim, self.range)
+    }
+
+    fn interaction(&mut self, ui: &Ui, response: &Response, travel: f32) {
+        if response.double_clicked() {
+            *self.trim = 0.0;
+            return;
+        }
+        let modifiers = ui.input(|input| input.modifiers);
+        let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+            self.fine_drag_ratio
+        } else {
+            1.0
+        };
+        let delta = response.drag_delta().x * ratio / travel;
+        if let Some(normalised) = drag_normalised(ui, response, self.normalised(), delta) {
+            let normalised = if self.detent && (normalised - 0.5).abs() < TRIM_DETENT {
+                0.5
+            } else {
+                normalised
+            };
+            *self.trim = lerp(-self.range..=self.range, normalised);
+        }
+    }
+
+    fn trim_ui(&self, ui: &Ui, response: &Response, rail_rect: Rect, handle: Vec2) {
+        let visuals = ui.style().interact(response);
+        let rail_height = 0.5 * ui.spacing().slider_rail_height;
+        let rail = Rect::from_center_size(rail_rect.center(), vec2(rail_rect.width(), rail_height));
+        let rail_corner = self
+            .style
+            .rail_corner_radius
+            .unwrap_or(ui.visuals().widgets.inactive.corner_radius);
+        let rail_colour = self
+            .style
+            .rail
+            .unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        ui.painter().rect_filled(rail, rail_corner, rail_colour);
+
+        let x_range = rail_rect.left() + 0.5 * handle.x..=rail_rect.right() - 0.5 * handle.x;
+        let centre = lerp(x_range.clone(), 0.5);
+        let x = lerp(x_range, self.normalised());
+
+        // Fill from 0 to the handle.
+        let fill_rect = Rect::from_x_y_ranges(Rangef::new(centre, x).as_positive(), rail.y_range());
+        let fill_colour = self
+            .style
+            .rail_fill
+            .unwrap_or(ui.visuals().selection.bg_fill);
+        ui.painter()
+            .rect_filled(fill_rect, rail_corner, fill_colour);
+        let centre_stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        ui.painter()
+            .vline(centre, rail_rect.y_range(), centre_stroke);
+
+        let handle_rect =
+            Rect::from_center_size(pos2(x, rail_rect.center().y), handle).expand(visuals.expansion);
+        ui.painter().rect(
+            handle_rect,
+            visuals.corner_radius,
+            self.style.handle_fill.unwrap_or(visuals.bg_fill),
+            self.style.handle_stroke.unwrap_or(visuals.fg_stroke),
+            epaint::StrokeKind::Inside,
+        );
+    }
+}
+
+impl Widget for Trim<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_trim = *self.trim;
+        // An out of range trim is written back clamped, and reported as a change.
+        *self.trim = self.trim.clamp(-self.range, self.range);
+        let width = self
+            .width
+            .unwrap_or_else(|| 1.5 * ui.spacing().interact_size.x);
+        let handle_height = self
+            .handle_size
+            .unwrap_or_else(|| 0.75 * ui.spacing().interact_size.y);
+        let handle = vec2(0.5 * handle_height, handle_height);
+        let text_height = if self.show_value {
+            self.text_size * 1.25
+        } else {
+            0.0
+        };
+        let size = vec2(width, handle_height + text_height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        self.interaction(ui, &response, (width - handle.x).max(1.0));
+        if ui.is_rect_visible(rect) {
+            let (rail_rect, text_rect) = rect.split_top_bottom_at_y(rect.top() + handle_height);
+            self.trim_ui(ui, &response, rail_rect, handle);
+            if self.show_value {
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    text_rect.center_bottom(),
+                    Align2::CENTER_BOTTOM,
+                    trim_text(*self.trim),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        if *self.trim != old_trim {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+fn normalised_from_trim(trim: f32, range: f32) -> f32 {
+    if range == 0.0 {
+        0.5
+    } else {
+        (trim / range * 0.5 + 0.5).clamp(0.0, 1.0)
+    }
+}
+
+/// The trim with an explicit sign, e.g. `+3.0`, and `0.0` in the centre.
+fn trim_text(trim: f32) -> String {
+    if trim.abs() < 0.05 {
+        "0.0".to_owned()
+    } else {
+        format!("{trim:+.1}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use egui::{Context, Event, PointerButton, Pos2, RawInput};
+
+    use super::*;
+
+    /// Show a trim for one frame with these input `events`, returning its rect and whether it
+    /// reported a change.
+    fn run(ctx: &Context, trim: &mut f32, range: f32, events: Vec<Event>) -> (Rect, bool) {
+        let mut shown = (Rect::NOTHING, false);
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.add(Trim::new(trim).range(range));
+                shown = (response.rect, response.changed());
+            });
+        });
+        shown
+    }
+
+    fn button(pos: Pos2, pressed: bool) -> Event {
+        Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn trim_is_centred_on_zero() {
+        assert_eq!(normalised_from_trim(0.0, 12.0), 0.5);
+        assert_eq!(normalised_from_trim(-12.0, 12.0), 0.0);
+        assert_eq!(normalised_from_trim(6.0, 12.0), 0.75);
+        assert_eq!(normalised_from_trim(24.0, 12.0), 1.0);
+    }
+
+    #[test]
+    fn trim_text_is_signed() {
+        assert_eq!(trim_text(3.0), "+3.0");
+        assert_eq!(trim_text(-1.26), "-1.3");
+        assert_eq!(trim_text(-0.01), "0.0");
+    }
+
+    #[test]
+    fn out_of_range_trim_is_clamped_as_a_change() {
+        let ctx = Context::default();
+        let mut trim = 6.0;
+        assert!(!run(&ctx, &mut trim, 12.0, Vec::new()).1);
+        // Shrinking the range leaves the trim outside it.
+        assert!(run(&ctx, &mut trim, 3.0, Vec::new()).1);
+        assert_eq!(trim, 3.0);
+        assert!(!run(&ctx, &mut trim, 3.0, Vec::new()).1);
+    }
+
+    #[test]
+    fn double_click_returns_to_zero() {
+        let ctx = Context::default();
+        let mut trim = 6.0;
+        let (rect, _) = run(&ctx, &mut trim, 12.0, Vec::new());
+        let pos = rect.center();
+        let clicks = vec![
+            Event::PointerMoved(pos),
+            button(pos, true),
+            button(pos, false),
+            button(pos, true),
+            button(pos, false),
+        ];
+        assert!(run(&ctx, &mut trim, 12.0, clicks).1);
+        assert_eq!(trim, 0.0);
+    }
+
+    #[test]
+    fn drags_near_zero_snap_to_it() {
+        let ctx = Context::default();
+        let mut trim = 0.0;
+        let (rect, _) = run(&ctx, &mut trim, 12.0, Vec::new());
+        let start = rect.center();
+        run(
+            &ctx,
+            &mut trim,
+            12.0,
+            vec![Event::PointerMoved(start), button(start, true)],
+        );
+        // Drag out of the detent then back to just beside zero.
+        let drag = start + vec2(10.0, 0.0);
+        assert!(run(&ctx, &mut trim, 12.0, vec![Event::PointerMoved(drag)]).1);
+        assert!(trim > 0.0);
+        let nudge = start + vec2(1.0, 0.0);
+        assert!(run(&ctx, &mut trim, 12.0, vec![Event::PointerMoved(nudge)]).1);
+        assert_eq!(trim, 0.0);
+    }
+}
@@ -0,0 +1,397 @@
+use std::borrow::Cow;
+
+use egui::{
+    Align2, FontId, Modifiers, Pos2, Rect, Response, Sense, Stroke, Ui, Vec2, Widget, emath,
+    epaint, lerp, pos2, remap_clamp, vec2,
+};
+
+use crate::{
+    FADER_FINE_DRAG_MODIFIERS, FADER_FINE_DRAG_RATIO, FaderStyle, GetSetValue, INFINITY,
+    any_modifier_held, get, normalised_from_value, set, value_from_normalised,
+};
+
+const XY_PAD_INCREMENTS: [f32; 3] = [-1.0, 0.0, 1.0];
+
+/// One axis of an [`XyPad`], mapped like a [`crate::Fader`].
+struct Axis<'a> {
+    get_set_value: GetSetValue<'a>,
+    integral: bool,
+    increments: Cow<'a, [f32]>,
+    segment_weights: Option<Cow<'a, [f32]>>,
+    neutral_level: f32,
+}
+
+impl<'a> Axis<'a> {
+    fn new<Num: emath::Numeric>(value: &'a mut Num) -> Self {
+        let get_set_value = move |v: Option<f64>| {
+            if let Some(v) = v {
+                *value = Num::from_f64(v);
+            }
+            value.to_f64()
+        };
+        Self {
+            get_set_value: Box::new(get_set_value),
+            integral: Num::INTEGRAL,
+            increments: Cow::Borrowed(&XY_PAD_INCREMENTS),
+            segment_weights: None,
+            neutral_level: 0.0,
+        }
+    }
+
+    fn normalise(&self, value: f32) -> f32 {
+        normalised_from_value(value, &self.increments, self.segment_weights.as_deref())
+    }
+
+    /// The pad has no `NEG_INFINITY`, the edge is the first increment.
+    fn denormalise(&self, normalised: f32) -> f32 {
+        let value = value_from_normalised(
+            normalised,
+            &self.increments,
+            self.segment_weights.as_deref(),
+        );
+        if value == -INFINITY {
+            self.increments[0]
+        } else {
+            value
+        }
+    }
+
+    fn get_value(&mut self) -> f64 {
+        get(&mut self.get_set_value)
+    }
+
+    fn set_value(&mut self, value: f64) {
+        let value = if self.integral { value.round() } else { value };
+        set(&mut self.get_set_value, value)
+    }
+
+    fn get_normalised(&mut self) -> f32 {
+        let value = self.get_value() as f32;
+        self.normalise(value)
+    }
+
+    fn set_normalised(&mut self, normalised: f32) {
+        self.set_value(self.denormalise(normalised) as f64)
+    }
+
+    fn set_to_neutral(&mut self) {
+        let min = self.increments[0];
+        let max = self.increments[self.increments.len() - 1];
+        self.set_value(self.neutral_level.clamp(min, max) as f64)
+    }
+}
+
+/// A square pad controlling two values at once, e.g. pan and width or a filter's cutoff and
+/// resonance.
+///
+/// Each axis uses the same increments mapping as a [`crate::Fader`], `x` increases to the
+/// right and `y` upwards. Click to jump the crosshair to the pointer, drag to move it and
+/// double click to return both values to neutral. An optional scope of recent points can be
+/// drawn behind the crosshair.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let (mut my_pan, mut my_width): (f32, f32) = (0.0, 1.0);
+/// ui.add(
+///     egui_fader::XyPad::new(&mut my_pan, &mut my_width)
+///         .y_increments(vec![0.0, 1.0, 2.0])
+///         .y_neutral_level(1.0),
+/// );
+/// # });
+/// ```
+pub struct XyPad<'a> {
+    x: Axis<'a>,
+    y: Axis<'a>,
+    size: Option<f32>,
+    scope: &'a [[f32; 2]],
+    fine_drag_modifiers: Modifiers,
+    fine_drag_ratio: f32,
+    show_value: bool,
+    text_size: f32,
+    style: FaderStyle,
+}
+
+impl<'a> XyPad<'a> {
+    /// Creates a pad controlling `x` horizontally and `y` vertically.
+    pub fn new<X: emath::Numeric, Y: emath::Numeric>(x: &'a mut X, y: &'a mut Y) -> Self {
+        Self {
+            x: Axis::new(x),
+            y: Axis::new(y),
+            size: None,
+            scope: &[],
+            fine_drag_modifiers: FADER_FINE_DRAG_MODIFIERS,
+            fine_drag_ratio: FADER_FINE_DRAG_RATIO,
+            show_value: true,
+            text_size: 10.0,
+            style: FaderStyle::default(),
+        }
+    }
+
+    /// Set the increments of the horizontal axis, see [`crate::Fader::increments`].
+    /// Default: `[-1, 0, 1]`.
+    #[inline]
+    pub fn x_increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.x.increments = increments.into();
+        self
+    }
+
+    /// Set the increments of the vertical axis, see [`crate::Fader::increments`].
+    /// Default: `[-1, 0, 1]`.
+    #[inline]
+    pub fn y_increments(mut self, increments: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.y.increments = increments.into();
+        self
+    }
+
+    /// Set the segment weights of the horizontal axis, see [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn x_segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.x.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the segment weights of the vertical axis, see [`crate::Fader::segment_weights`].
+    #[inline]
+    pub fn y_segment_weights(mut self, segment_weights: impl Into<Cow<'a, [f32]>>) -> Self {
+        self.y.segment_weights = Some(segment_weights.into());
+        self
+    }
+
+    /// Set the horizontal value double clicking returns to. Default: `0.0`.
+    #[inline]
+    pub fn x_neutral_level(mut self, neutral_level: f32) -> Self {
+        self.x.neutral_level = neutral_level;
+        self
+    }
+
+    /// Set the vertical value double clicking returns to. Default: `0.0`.
+    #[inline]
+    pub fn y_neutral_level(mut self, neutral_level: f32) -> Self {
+        self.y.neutral_level = neutral_level;
+        self
+    }
+
+    /// Set the width and height of the pad in points.
+    /// The default is `egui::Ui.spacing().slider_width`.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Draw recent `[x, y]` points behind the crosshair, oldest first, fading with age.
+    /// Points are in the units of the axes, e.g. the measured pan and width of a signal.
+    #[inline]
+    pub fn scope(mut self, scope: &'a [[f32; 2]]) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Set the modifiers that enable fine drag, see [`crate::Fader::fine_drag_modifiers`].
+    #[inline]
+    pub fn fine_drag_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.fine_drag_modifiers = modifiers;
+        self
+    }
+
+    /// Set the ratio applied to drags while a fine drag modifier is held.
+    #[inline]
+    pub fn fine_drag_ratio(mut self, ratio: f32) -> Self {
+        self.fine_drag_ratio = ratio;
+        self
+    }
+
+    /// Show both values underneath the pad. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Set the size of the text displayed on the widget.
+    #[inline]
+    pub fn text_size(mut self, text_size: f32) -> Self {
+        self.text_size = text_size;
+        self
+    }
+
+    /// Set the colours of the pad. The `channel` colour is the background, `rail` the
+    /// crosshair, `handle_fill` and `handle_stroke` the handle and `signal` the scope.
+    #[inline]
+    pub fn style(mut self, style: FaderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn normalised(&mut self) -> Vec2 {
+        vec2(self.x.get_normalised(), self.y.get_normalised())
+    }
+
+    fn set_normalised(&mut self, normalised: Vec2) {
+        self.x.set_normalised(normalised.x);
+        self.y.set_normalised(normalised.y);
+    }
+
+    fn interaction(&mut self, ui: &Ui, response: &Response, rect: Rect) {
+        if response.double_clicked() {
+            self.x.set_to_neutral();
+            self.y.set_to_neutral();
+            return;
+        }
+        if response.clicked()
+            && let Some(pointer) = response.interact_pointer_pos()
+        {
+            self.set_normalised(normalised_from_pos(rect, pointer));
+            return;
+        }
+        // Keep the unrounded position during the drag so fine movements aren't lost.
+        let id = response.id.with("xy");
+        if response.drag_started() {
+            let normalised = self.normalised();
+            ui.data_mut(|data| data.insert_temp(id, normalised));
+        }
+        let delta = response.drag_delta();
+        if response.dragged() && delta != Vec2::ZERO {
+            let modifiers = ui.input(|input| input.modifiers);
+            let ratio = if any_modifier_held(modifiers, self.fine_drag_modifiers) {
+                self.fine_drag_ratio
+            } else {
+                1.0
+            };
+            let start = ui
+                .data(|data| data.get_temp::<Vec2>(id))
+                .unwrap_or_else(|| self.normalised());
+            let delta = ratio * vec2(delta.x / rect.width(), -delta.y / rect.height());
+            let normalised = (start + delta).clamp(Vec2::ZERO, Vec2::splat(1.0));
+            ui.data_mut(|data| data.insert_temp(id, normalised));
+            self.set_normalised(normalised);
+        }
+        if response.drag_stopped() {
+            ui.data_mut(|data| data.remove::<Vec2>(id));
+        }
+    }
+
+    fn pad_ui(&mut self, ui: &Ui, response: &Response, rect: Rect) {
+        let visuals = ui.style().interact(response);
+        let corner = self
+            .style
+            .meter_corner_radius
+            .unwrap_or(ui.visuals().widgets.inactive.corner_radius);
+        let background = self.style.channel.unwrap_or(ui.visuals().extreme_bg_color);
+        ui.painter().rect_filled(rect, corner, background);
+        self.scope_ui(ui, rect);
+
+        let normalised = self.normalised();
+        let handle = pos_from_normalised(rect, normalised);
+        let crosshair_colour = self
+            .style
+            .rail
+            .unwrap_or(ui.visuals().widgets.inactive.bg_fill);
+        let crosshair = Stroke::new(1.0, crosshair_colour);
+        ui.painter().hline(rect.x_range(), handle.y, crosshair);
+        ui.painter().vline(handle.x, rect.y_range(), crosshair);
+
+        let handle_fill = self.style.handle_fill.unwrap_or(visuals.bg_fill);
+        let handle_stroke = self.style.handle_stroke.unwrap_or(visuals.fg_stroke);
+        ui.painter().add(epaint::CircleShape {
+            center: handle,
+            radius: 0.5 * ui.spacing().interact_size.y + visuals.expansion,
+            fill: handle_fill,
+            stroke: handle_stroke,
+        });
+    }
+
+    fn scope_ui(&self, ui: &Ui, rect: Rect) {
+        let colour = self
+            .style
+            .signal
+            .unwrap_or(ui.visuals().widgets.active.fg_stroke.color);
+        let count = self.scope.len() as f32;
+        let painter = ui.painter().with_clip_rect(rect);
+        for (age, &[x, y]) in self.scope.iter().enumerate() {
+            let normalised = vec2(self.x.normalise(x), self.y.normalise(y));
+            let fade = (age + 1) as f32 / count;
+            painter.circle_filled(
+                pos_from_normalised(rect, normalised),
+                1.5,
+                colour.gamma_multiply(fade),
+            );
+        }
+    }
+
+    fn value_text(&mut self) -> String {
+        format!("{:.2}, {:.2}", self.x.get_value(), self.y.get_value())
+    }
+}
+
+impl Widget for XyPad<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let size = self.size.unwrap_or_else(|| ui.spacing().slider_width);
+        let text_height = if self.show_value {
+            self.text_size * 1.25
+        } else {
+            0.0
+        };
+        let (rect, mut response) =
+            ui.allocate_exact_size(vec2(size, size + text_height), Sense::click_and_drag());
+        let pad_rect = Rect::from_min_size(rect.min, Vec2::splat(size));
+        let old_values = (self.x.get_value(), self.y.get_value());
+        self.interaction(ui, &response, pad_rect);
+        if ui.is_rect_visible(rect) {
+            self.pad_ui(ui, &response, pad_rect);
+            if self.show_value {
+                let text_colour = self.style.readout_text.unwrap_or(ui.visuals().text_color());
+                ui.painter().text(
+                    rect.center_bottom(),
+                    Align2::CENTER_BOTTOM,
+                    self.value_text(),
+                    FontId::proportional(self.text_size),
+                    text_colour,
+                );
+            }
+        }
+        if (self.x.get_value(), self.y.get_value()) != old_values {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Normalised position of a point on the pad, with `y` increasing upwards.
+fn normalised_from_pos(rect: Rect, pos: Pos2) -> Vec2 {
+    vec2(
+        remap_clamp(pos.x, rect.x_range(), 0.0..=1.0),
+        remap_clamp(pos.y, rect.bottom()..=rect.top(), 0.0..=1.0),
+    )
+}
+
+fn pos_from_normalised(rect: Rect, normalised: Vec2) -> Pos2 {
+    pos2(
+        lerp(rect.x_range(), normalised.x),
+        lerp(rect.bottom()..=rect.top(), normalised.y),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pad_positions_round_trip_with_y_upwards() {
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), Vec2::splat(100.0));
+        let top_right = normalised_from_pos(rect, rect.right_top());
+        assert_eq!(top_right, vec2(1.0, 1.0));
+        let normalised = vec2(0.25, 0.75);
+        let pos = pos_from_normalised(rect, normalised);
+        assert_eq!(normalised_from_pos(rect, pos), normalised);
+    }
+
+    #[test]
+    fn axes_stop_at_the_first_increment() {
+        let (mut x, mut y): (f32, f32) = (0.0, 0.0);
+        let pad = XyPad::new(&mut x, &mut y).y_increments(vec![0.0, 1.0, 2.0]);
+        assert_eq!(pad.x.denormalise(0.0), -1.0);
+        assert_eq!(pad.y.denormalise(0.0), 0.0);
+        assert_eq!(pad.y.denormalise(pad.y.normalise(1.5)), 1.5);
+    }
+}